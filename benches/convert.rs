@@ -0,0 +1,29 @@
+use criterion::{black_box, criterion_group, criterion_main, Criterion};
+use velas_address_rust::*;
+
+fn convert(c: &mut Criterion) {
+    c.bench_function("eth_to_vlx", |b| {
+        b.iter(|| eth_to_vlx(black_box("0x32Be343B94f860124dC4fEe278FDCBD38C102D88")))
+    });
+
+    c.bench_function("vlx_to_eth", |b| {
+        b.iter(|| vlx_to_eth(black_box("V5dJeCa7bmkqmZF53TqjRbnB4fG6hxuu4f")))
+    });
+
+    c.bench_function("vlx_to_eth padded", |b| {
+        b.iter(|| vlx_to_eth(black_box("V111111111111111111111111112jSS6vy")))
+    });
+
+    c.bench_function("decode_vlx", |b| {
+        b.iter(|| decode_vlx(black_box("V5dJeCa7bmkqmZF53TqjRbnB4fG6hxuu4f")))
+    });
+
+    let address = decode_vlx("V5dJeCa7bmkqmZF53TqjRbnB4fG6hxuu4f").unwrap();
+    c.bench_function("encode_vlx_into", |b| {
+        let mut buf = [0u8; 34];
+        b.iter(|| encode_vlx_into(black_box(&address), &mut buf).len())
+    });
+}
+
+criterion_group!(benches, convert);
+criterion_main!(benches);
@@ -0,0 +1,20 @@
+#![no_main]
+use libfuzzer_sys::fuzz_target;
+use velas_address_rust::*;
+
+fuzz_target!(|data: &[u8]| {
+    if let Ok(address) = std::str::from_utf8(data) {
+        for lenient in [false, true].iter() {
+            for checksum in [false, true].iter() {
+                for chain_id in [None, Some(VELAS_MAINNET_CHAIN_ID)].iter() {
+                    let options = Options::new()
+                        .lenient(*lenient)
+                        .checksum(*checksum)
+                        .chain_id(*chain_id);
+                    let _ = eth_to_vlx_with(address, options);
+                    let _ = vlx_to_eth_with(address, options);
+                }
+            }
+        }
+    }
+});
@@ -0,0 +1,99 @@
+#ifndef VELAS_ADDRESS_H
+#define VELAS_ADDRESS_H
+
+/* Generated with cbindgen from src/ffi.rs, do not edit */
+
+#include <stddef.h>
+#include <stdint.h>
+
+/**
+ * Accept addresses of any length
+ */
+#define VELAS_FLAG_LENIENT 1
+
+/**
+ * Verify (`velas_eth_to_vlx`) or produce (`velas_vlx_to_eth`) the EIP-55 checksum
+ */
+#define VELAS_FLAG_CHECKSUM 2
+
+/**
+ * Buffer size that fits a converted 20-byte address including the NUL terminator
+ */
+#define VELAS_ADDRESS_MAX_LEN 64
+
+#define VELAS_OK 0
+
+#define VELAS_ERROR_MISSING_PREFIX 1
+
+#define VELAS_ERROR_INVALID_LENGTH 2
+
+#define VELAS_ERROR_INVALID_CHARACTER 3
+
+#define VELAS_ERROR_CHECKSUM_MISMATCH 4
+
+#define VELAS_ERROR_NON_CANONICAL_PADDING 5
+
+#define VELAS_ERROR_INVALID_CHECKSUM_CASE 6
+
+#define VELAS_ERROR_INVALID_KEY 7
+
+#define VELAS_ERROR_INVALID_MNEMONIC 8
+
+#define VELAS_ERROR_INVALID_DERIVATION_PATH 9
+
+#define VELAS_ERROR_NON_CANONICAL 10
+
+#define VELAS_ERROR_ODD_LENGTH 11
+
+/**
+ * A pointer argument is NULL
+ */
+#define VELAS_ERROR_NULL_POINTER -1
+
+/**
+ * The input is not valid UTF-8
+ */
+#define VELAS_ERROR_INVALID_UTF8 -2
+
+/**
+ * The output buffer is too small for the result
+ */
+#define VELAS_ERROR_BUFFER_TOO_SMALL -3
+
+
+
+/**
+ * Convert ETH address to VLX address
+ *
+ * # Safety
+ *
+ * `address` must be NULL or a NUL-terminated string, `out` must be NULL or
+ * point to at least `out_len` writable bytes.
+ */
+int velas_eth_to_vlx(const char *address, uint32_t flags, char *out, size_t out_len);
+
+/**
+ * Convert VLX address to ETH address
+ *
+ * # Safety
+ *
+ * `address` must be NULL or a NUL-terminated string, `out` must be NULL or
+ * point to at least `out_len` writable bytes.
+ */
+int velas_vlx_to_eth(const char *address, uint32_t flags, char *out, size_t out_len);
+
+/**
+ * Check that an address is valid in any supported format
+ *
+ * # Safety
+ *
+ * `address` must be NULL or a NUL-terminated string.
+ */
+int velas_validate(const char *address);
+
+/**
+ * Static NUL-terminated description of an error code, never NULL
+ */
+const char *velas_error_message(int code);
+
+#endif  /* VELAS_ADDRESS_H */
@@ -0,0 +1,245 @@
+use crate::{
+    base58, decode_base58, decode_vlx, eip55, encode_base58, encode_vlx_into, find_invalid_char,
+    strip_hex_prefix, AddressError,
+};
+use alloc::format;
+use alloc::string::String;
+use core::convert::TryFrom;
+use core::fmt;
+use core::str::FromStr;
+
+fn to_array<const N: usize>(bytes: &[u8]) -> Result<[u8; N], AddressError> {
+    if bytes.len() != N {
+        return Err(AddressError::InvalidLength {
+            expected: N,
+            found: bytes.len(),
+        });
+    }
+
+    let mut array = [0u8; N];
+    array.copy_from_slice(bytes);
+    Ok(array)
+}
+
+/// ETH address in `0x` format
+///
+/// ```rust
+/// use velas_address_rust::*;
+///
+/// let eth: EthAddress = "0x32Be343B94f860124dC4fEe278FDCBD38C102D88".parse().unwrap();
+/// assert_eq!(eth.to_string(), "0x32be343b94f860124dc4fee278fdcbd38c102d88");
+/// assert_eq!(VlxAddress::from(eth).to_string(), "V5dJeCa7bmkqmZF53TqjRbnB4fG6hxuu4f");
+/// ```
+///
+#[derive(Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct EthAddress([u8; 20]);
+
+impl EthAddress {
+    /// Raw 20 bytes of the address
+    pub fn as_bytes(&self) -> &[u8; 20] {
+        &self.0
+    }
+
+    /// Address in EIP-55 mixed-case checksum format
+    ///
+    /// ```rust
+    /// use velas_address_rust::*;
+    ///
+    /// let eth: EthAddress = "0x32be343b94f860124dc4fee278fdcbd38c102d88".parse().unwrap();
+    /// assert_eq!(eth.to_checksum(), "0x32Be343B94f860124dC4fEe278FDCBD38C102D88");
+    /// ```
+    ///
+    pub fn to_checksum(&self) -> String {
+        format!("0x{}", eip55::to_checksum(&hex::encode(self.0), None))
+    }
+
+    /// Address in EIP-1191 chain-specific mixed-case checksum format
+    ///
+    /// ```rust
+    /// use velas_address_rust::*;
+    ///
+    /// let eth: EthAddress = "0x5aaeb6053f3e94c9b9a09f33669435e7ef1beaed".parse().unwrap();
+    /// assert_eq!(eth.to_checksum_with_chain_id(30), "0x5aaEB6053f3e94c9b9a09f33669435E7ef1bEAeD");
+    /// ```
+    ///
+    pub fn to_checksum_with_chain_id(&self, chain_id: u64) -> String {
+        format!(
+            "0x{}",
+            eip55::to_checksum(&hex::encode(self.0), Some(chain_id))
+        )
+    }
+}
+
+impl FromStr for EthAddress {
+    type Err = AddressError;
+
+    fn from_str(address: &str) -> Result<Self, Self::Err> {
+        let clear_addr = strip_hex_prefix(address)?;
+
+        if clear_addr.len() % 2 != 0 {
+            return Err(AddressError::OddLength);
+        }
+
+        if clear_addr.len() != 40 {
+            return Err(AddressError::InvalidLength {
+                expected: 20,
+                found: clear_addr.len() / 2,
+            });
+        }
+
+        let mut bytes = [0u8; 20];
+        hex::decode_to_slice(clear_addr, &mut bytes).unwrap();
+        Ok(EthAddress(bytes))
+    }
+}
+
+impl TryFrom<&[u8]> for EthAddress {
+    type Error = AddressError;
+
+    fn try_from(bytes: &[u8]) -> Result<Self, Self::Error> {
+        Ok(EthAddress(to_array(bytes)?))
+    }
+}
+
+impl From<VlxAddress> for EthAddress {
+    fn from(address: VlxAddress) -> Self {
+        EthAddress(address.0)
+    }
+}
+
+impl fmt::Display for EthAddress {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let mut buf = [0u8; 40];
+        hex::encode_to_slice(self.0, &mut buf).unwrap();
+        write!(f, "0x{}", core::str::from_utf8(&buf).unwrap())
+    }
+}
+
+impl fmt::Debug for EthAddress {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "EthAddress({})", self)
+    }
+}
+
+/// VLX address in `V` format
+///
+/// ```rust
+/// use velas_address_rust::*;
+///
+/// let vlx: VlxAddress = "V5dJeCa7bmkqmZF53TqjRbnB4fG6hxuu4f".parse().unwrap();
+/// assert_eq!(vlx.to_string(), "V5dJeCa7bmkqmZF53TqjRbnB4fG6hxuu4f");
+/// assert_eq!(EthAddress::from(vlx).to_string(), "0x32be343b94f860124dc4fee278fdcbd38c102d88");
+/// ```
+///
+#[derive(Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct VlxAddress([u8; 20]);
+
+impl VlxAddress {
+    /// Raw 20 bytes of the address
+    pub fn as_bytes(&self) -> &[u8; 20] {
+        &self.0
+    }
+}
+
+impl FromStr for VlxAddress {
+    type Err = AddressError;
+
+    fn from_str(address: &str) -> Result<Self, Self::Err> {
+        Ok(VlxAddress(decode_vlx(address)?))
+    }
+}
+
+impl TryFrom<&[u8]> for VlxAddress {
+    type Error = AddressError;
+
+    fn try_from(bytes: &[u8]) -> Result<Self, Self::Error> {
+        Ok(VlxAddress(to_array(bytes)?))
+    }
+}
+
+impl From<EthAddress> for VlxAddress {
+    fn from(address: EthAddress) -> Self {
+        VlxAddress(address.0)
+    }
+}
+
+impl fmt::Display for VlxAddress {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let mut buf = [0u8; 34];
+        f.write_str(encode_vlx_into(&self.0, &mut buf))
+    }
+}
+
+impl fmt::Debug for VlxAddress {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "VlxAddress({})", self)
+    }
+}
+
+/// Velas native address, a 32-byte ed25519 public key in plain base58
+///
+/// The key is not checked to be on the curve, since program derived
+/// addresses are valid accounts too. A `V` address never parses as a native
+/// address and vice versa, because their decoded lengths differ.
+///
+/// ```rust
+/// use velas_address_rust::*;
+///
+/// let native: NativeAddress = "Vote111111111111111111111111111111111111111".parse().unwrap();
+/// assert_eq!(native.to_string(), "Vote111111111111111111111111111111111111111");
+/// assert!("V5dJeCa7bmkqmZF53TqjRbnB4fG6hxuu4f".parse::<NativeAddress>().is_err());
+/// ```
+///
+#[derive(Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct NativeAddress([u8; 32]);
+
+impl NativeAddress {
+    /// Raw 32 bytes of the public key
+    pub fn as_bytes(&self) -> &[u8; 32] {
+        &self.0
+    }
+}
+
+/// Length of the longest base58 encoding of 32 bytes
+const MAX_NATIVE_LEN: usize = 44;
+
+impl FromStr for NativeAddress {
+    type Err = AddressError;
+
+    fn from_str(address: &str) -> Result<Self, Self::Err> {
+        // More digits always decode to more than 32 bytes, reject them before
+        // decoding, which takes time quadratic in the length
+        if address.len() > MAX_NATIVE_LEN {
+            if let Some(err) = find_invalid_char(address, base58::ALPHABET, 0) {
+                return Err(err);
+            }
+
+            return Err(AddressError::InvalidLength {
+                expected: 32,
+                found: base58::min_decoded_len(address.as_bytes(), base58::ALPHABET),
+            });
+        }
+
+        Ok(NativeAddress(to_array(&decode_base58(address)?)?))
+    }
+}
+
+impl TryFrom<&[u8]> for NativeAddress {
+    type Error = AddressError;
+
+    fn try_from(bytes: &[u8]) -> Result<Self, Self::Error> {
+        Ok(NativeAddress(to_array(bytes)?))
+    }
+}
+
+impl fmt::Display for NativeAddress {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(&encode_base58(&self.0))
+    }
+}
+
+impl fmt::Debug for NativeAddress {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "NativeAddress({})", self)
+    }
+}
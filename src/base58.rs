@@ -0,0 +1,108 @@
+//! Base58 with the Bitcoin alphabet, working on caller-provided buffers
+
+pub(crate) const ALPHABET: &[u8; 58] =
+    b"123456789ABCDEFGHJKLMNPQRSTUVWXYZabcdefghijkmnopqrstuvwxyz";
+
+const INVALID: u8 = 0xff;
+
+/// Digit values of the characters of `alphabet`, `INVALID` for the others
+const fn decode_table(alphabet: &[u8; 58]) -> [u8; 128] {
+    let mut table = [INVALID; 128];
+    let mut i = 0;
+    while i < alphabet.len() {
+        table[alphabet[i] as usize] = i as u8;
+        i += 1;
+    }
+    table
+}
+
+const DECODE: [u8; 128] = decode_table(ALPHABET);
+
+/// Maximum length of the encoding of `len` bytes
+pub(crate) const fn encoded_len(len: usize) -> usize {
+    len * 138 / 100 + 1
+}
+
+/// Minimum number of bytes that `input` decodes to
+///
+/// Leading zero digits are zero bytes, the other k digits need at least
+/// (k - 1) * log256(58) + 1 bytes.
+pub(crate) fn min_decoded_len(input: &[u8], alphabet: &[u8; 58]) -> usize {
+    let zeros = input.iter().take_while(|ch| **ch == alphabet[0]).count();
+    zeros
+        + (input.len() - zeros)
+            .checked_sub(1)
+            .map_or(0, |k| k * 7322 / 10000 + 1)
+}
+
+/// Encode `input` with the digits of `alphabet` into the start of `output`
+/// and return the number of characters
+///
+/// Every leading zero byte becomes a leading zero digit. `output` must hold
+/// at least `encoded_len(input.len())` bytes.
+pub(crate) fn encode_into(input: &[u8], alphabet: &[u8; 58], output: &mut [u8]) -> usize {
+    let mut len = 0;
+
+    for byte in input {
+        let mut carry = *byte as u32;
+        for digit in output[..len].iter_mut() {
+            carry += (*digit as u32) << 8;
+            *digit = (carry % 58) as u8;
+            carry /= 58;
+        }
+        while carry > 0 {
+            output[len] = (carry % 58) as u8;
+            len += 1;
+            carry /= 58;
+        }
+    }
+
+    for _ in input.iter().take_while(|byte| **byte == 0) {
+        output[len] = 0;
+        len += 1;
+    }
+
+    output[..len].reverse();
+    for digit in output[..len].iter_mut() {
+        *digit = alphabet[*digit as usize];
+    }
+
+    len
+}
+
+/// Decode `input` with the digits of `alphabet` into the start of `output`
+/// and return the number of bytes
+///
+/// Every leading zero digit becomes a leading zero byte. `input` must only
+/// contain characters of `alphabet`, `None` is returned if `output` is too small.
+pub(crate) fn decode_into(input: &[u8], alphabet: &[u8; 58], output: &mut [u8]) -> Option<usize> {
+    let table = if alphabet == ALPHABET {
+        DECODE
+    } else {
+        decode_table(alphabet)
+    };
+    let mut len = 0;
+
+    for ch in input {
+        let mut carry = table[*ch as usize] as u32;
+        debug_assert!(carry != INVALID as u32);
+        for byte in output[..len].iter_mut() {
+            carry += (*byte as u32) * 58;
+            *byte = carry as u8;
+            carry >>= 8;
+        }
+        while carry > 0 {
+            *output.get_mut(len)? = carry as u8;
+            len += 1;
+            carry >>= 8;
+        }
+    }
+
+    for _ in input.iter().take_while(|ch| **ch == alphabet[0]) {
+        *output.get_mut(len)? = 0;
+        len += 1;
+    }
+
+    output[..len].reverse();
+    Some(len)
+}
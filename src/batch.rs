@@ -0,0 +1,309 @@
+use crate::{eth_to_vlx_with, vlx_to_eth_with, AddressError, Options};
+use serde_json::{Map, Value};
+use std::error::Error;
+use std::fmt;
+use std::io::{self, BufRead, Read, Write};
+
+/// Conversion direction of a batch
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Direction {
+    /// ETH addresses to VLX addresses
+    ToVlx,
+    /// VLX addresses to ETH addresses
+    ToEth,
+}
+
+/// Reason a single row could not be converted
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum RowErrorKind {
+    /// The address is invalid
+    Address(AddressError),
+    /// The row has no string value in the address column
+    MissingField,
+    /// The row is not a JSON object, not UTF-8 or has the wrong number of CSV fields
+    Malformed(String),
+}
+
+impl fmt::Display for RowErrorKind {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            RowErrorKind::Address(err) => write!(f, "{}", err),
+            RowErrorKind::MissingField => write!(f, "Missing address field"),
+            RowErrorKind::Malformed(err) => write!(f, "Malformed row: {}", err),
+        }
+    }
+}
+
+/// Error of a single row, `row` counts data rows starting at 1
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct RowError {
+    pub row: usize,
+    pub error: RowErrorKind,
+}
+
+/// Summary of a finished batch
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct BatchReport {
+    /// Number of data rows processed
+    pub rows: usize,
+    /// Rows that could not be converted
+    pub errors: Vec<RowError>,
+}
+
+/// Error that aborts a batch
+#[derive(Debug)]
+pub enum BatchError {
+    /// Reading or writing failed
+    Io(io::Error),
+    /// The CSV header is malformed
+    Csv(csv::Error),
+    /// The CSV header has no column with this name
+    MissingColumn(String),
+}
+
+impl fmt::Display for BatchError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            BatchError::Io(err) => write!(f, "{}", err),
+            BatchError::Csv(err) => write!(f, "{}", err),
+            BatchError::MissingColumn(column) => write!(f, "missing column {:?}", column),
+        }
+    }
+}
+
+impl Error for BatchError {}
+
+impl From<io::Error> for BatchError {
+    fn from(err: io::Error) -> Self {
+        BatchError::Io(err)
+    }
+}
+
+impl From<csv::Error> for BatchError {
+    fn from(err: csv::Error) -> Self {
+        BatchError::Csv(err)
+    }
+}
+
+/// Converts an address column of CSV or JSON Lines input
+///
+/// Every row gets two extra columns, the converted address (empty on error)
+/// and a status, which is `valid` or the error message. Rows with invalid
+/// addresses, without the address or that are malformed are reported in the
+/// [`BatchReport`] and do not abort the batch.
+///
+/// ```rust
+/// use velas_address_rust::*;
+///
+/// let input = "id,address\n1,0x32Be343B94f860124dC4fEe278FDCBD38C102D88\n2,0x12\n";
+/// let mut output = Vec::new();
+///
+/// let report = BatchConverter::new("address", Direction::ToVlx)
+///     .convert_csv(input.as_bytes(), &mut output)
+///     .unwrap();
+///
+/// assert_eq!(report.rows, 2);
+/// assert_eq!(report.errors.len(), 1);
+/// assert_eq!(
+///     String::from_utf8(output).unwrap(),
+///     "id,address,address_vlx,address_status\n\
+///      1,0x32Be343B94f860124dC4fEe278FDCBD38C102D88,V5dJeCa7bmkqmZF53TqjRbnB4fG6hxuu4f,valid\n\
+///      2,0x12,,\"Invalid address: expected 20 bytes, found 1\"\n"
+/// );
+/// ```
+///
+#[derive(Clone, Debug)]
+pub struct BatchConverter {
+    column: String,
+    direction: Direction,
+    options: Options,
+    output_column: String,
+    status_column: String,
+}
+
+impl BatchConverter {
+    /// Converter for the named column, new columns are called `<column>_vlx`
+    /// or `<column>_eth` and `<column>_status`
+    pub fn new(column: &str, direction: Direction) -> Self {
+        let suffix = match direction {
+            Direction::ToVlx => "vlx",
+            Direction::ToEth => "eth",
+        };
+
+        BatchConverter {
+            column: column.to_string(),
+            direction,
+            options: Options::default(),
+            output_column: format!("{}_{}", column, suffix),
+            status_column: format!("{}_status", column),
+        }
+    }
+
+    /// Options passed to `eth_to_vlx_with` or `vlx_to_eth_with`
+    pub fn options(mut self, options: Options) -> Self {
+        self.options = options;
+        self
+    }
+
+    /// Name of the column with the converted address
+    pub fn output_column(mut self, column: &str) -> Self {
+        self.output_column = column.to_string();
+        self
+    }
+
+    /// Name of the column with the validation status
+    pub fn status_column(mut self, column: &str) -> Self {
+        self.status_column = column.to_string();
+        self
+    }
+
+    fn convert(&self, address: Option<&str>) -> Result<String, RowErrorKind> {
+        let address = address.ok_or(RowErrorKind::MissingField)?;
+
+        match self.direction {
+            Direction::ToVlx => eth_to_vlx_with(address, self.options),
+            Direction::ToEth => vlx_to_eth_with(address, self.options),
+        }
+        .map_err(RowErrorKind::Address)
+    }
+
+    /// Convert CSV with a header row
+    pub fn convert_csv<R: Read, W: Write>(
+        &self,
+        reader: R,
+        writer: W,
+    ) -> Result<BatchReport, BatchError> {
+        let mut reader = csv::ReaderBuilder::new().flexible(true).from_reader(reader);
+        let mut writer = csv::Writer::from_writer(writer);
+        let mut report = BatchReport::default();
+
+        let mut headers = reader.headers()?.clone();
+        let index = headers
+            .iter()
+            .position(|header| header == self.column)
+            .ok_or_else(|| BatchError::MissingColumn(self.column.clone()))?;
+
+        let fields = headers.len();
+        headers.push_field(&self.output_column);
+        headers.push_field(&self.status_column);
+        writer.write_record(&headers)?;
+
+        for record in reader.records() {
+            report.rows += 1;
+
+            // Malformed rows are written with as many fields as the header
+            // to keep the columns aligned
+            let result = match record {
+                Ok(record) if record.len() == fields => Ok(record),
+                Ok(record) => {
+                    let error = format!("expected {} fields, found {}", fields, record.len());
+                    let mut record: csv::StringRecord = record.iter().take(fields).collect();
+                    while record.len() < fields {
+                        record.push_field("");
+                    }
+                    Err((record, RowErrorKind::Malformed(error)))
+                }
+                Err(err) if err.is_io_error() => return Err(err.into()),
+                Err(err) => {
+                    let record = (0..fields).map(|_| "").collect();
+                    Err((record, RowErrorKind::Malformed(err.to_string())))
+                }
+            };
+
+            let mut record = match result {
+                Ok(record) => record,
+                Err((mut record, error)) => {
+                    record.push_field("");
+                    record.push_field(&error.to_string());
+                    report.errors.push(RowError {
+                        row: report.rows,
+                        error,
+                    });
+                    writer.write_record(&record)?;
+                    continue;
+                }
+            };
+
+            match self.convert(record.get(index)) {
+                Ok(address) => {
+                    record.push_field(&address);
+                    record.push_field("valid");
+                }
+                Err(error) => {
+                    record.push_field("");
+                    record.push_field(&error.to_string());
+                    report.errors.push(RowError {
+                        row: report.rows,
+                        error,
+                    });
+                }
+            }
+
+            writer.write_record(&record)?;
+        }
+
+        writer.flush()?;
+        Ok(report)
+    }
+
+    /// Convert JSON Lines, one object per line, empty lines are skipped
+    ///
+    /// Objects without the column or with a non-string value get the
+    /// [`RowErrorKind::MissingField`] status. Lines that are not a JSON object
+    /// are written as an object with only the two new fields.
+    pub fn convert_json_lines<R: BufRead, W: Write>(
+        &self,
+        reader: R,
+        mut writer: W,
+    ) -> Result<BatchReport, BatchError> {
+        let mut report = BatchReport::default();
+
+        for line in reader.lines() {
+            let line = match line {
+                Ok(line) => Ok(line),
+                Err(err) if err.kind() == io::ErrorKind::InvalidData => Err(err.to_string()),
+                Err(err) => return Err(err.into()),
+            };
+            if line.as_ref().is_ok_and(|line| line.trim().is_empty()) {
+                continue;
+            }
+            report.rows += 1;
+
+            let parsed = line.and_then(|line| match serde_json::from_str::<Value>(&line) {
+                Ok(Value::Object(object)) => Ok(object),
+                Ok(_) => Err("expected a JSON object".to_string()),
+                Err(err) => Err(err.to_string()),
+            });
+
+            let (mut object, result) = match parsed {
+                Ok(object) => {
+                    let address = object.get(&self.column).and_then(Value::as_str);
+                    let result = self.convert(address);
+                    (object, result)
+                }
+                Err(err) => (Map::new(), Err(RowErrorKind::Malformed(err))),
+            };
+
+            match result {
+                Ok(address) => {
+                    object.insert(self.output_column.clone(), Value::String(address));
+                    object.insert(self.status_column.clone(), Value::from("valid"));
+                }
+                Err(error) => {
+                    object.insert(self.output_column.clone(), Value::Null);
+                    object.insert(self.status_column.clone(), Value::String(error.to_string()));
+                    report.errors.push(RowError {
+                        row: report.rows,
+                        error,
+                    });
+                }
+            }
+
+            serde_json::to_writer(&mut writer, &object).map_err(io::Error::from)?;
+            writer.write_all(b"\n")?;
+        }
+
+        writer.flush()?;
+        Ok(report)
+    }
+}
@@ -0,0 +1,381 @@
+//! Command-line tool for converting and validating Velas addresses
+//!
+//! ```text
+//! velas-address to-vlx 0x32Be343B94f860124dC4fEe278FDCBD38C102D88
+//! echo V5dJeCa7bmkqmZF53TqjRbnB4fG6hxuu4f | velas-address --json to-eth
+//! velas-address vanity --prefix VLX
+//! ```
+//!
+use ::serde::Serialize;
+use clap::{Args, Parser, Subcommand, ValueEnum};
+use serde_json::json;
+use std::io::{self, BufRead, BufWriter, Write};
+use std::process;
+use std::time::Duration;
+use velas_address_rust::*;
+use zeroize::Zeroizing;
+
+#[derive(Parser)]
+#[command(
+    name = "velas-address",
+    version,
+    about = "Convert and validate Velas addresses"
+)]
+struct Cli {
+    /// Print one JSON object per address
+    #[arg(long, global = true)]
+    json: bool,
+
+    #[command(subcommand)]
+    command: Command,
+}
+
+#[derive(Subcommand)]
+enum Command {
+    /// Convert ETH addresses to VLX addresses
+    ToVlx(ConvertArgs),
+    /// Convert VLX addresses to ETH addresses
+    ToEth(ConvertArgs),
+    /// Check that addresses are valid in any supported format
+    Validate(InputArgs),
+    /// Print the format and decoded payload of addresses
+    Detect(InputArgs),
+    /// Convert an address column of CSV or JSON Lines from stdin to stdout
+    Batch(BatchArgs),
+    /// Generate a private key whose VLX address has a custom prefix or suffix
+    Vanity(VanityArgs),
+}
+
+#[derive(Args)]
+struct InputArgs {
+    /// Addresses to process, read newline-delimited from stdin if omitted
+    addresses: Vec<String>,
+}
+
+#[derive(Args)]
+struct OptionArgs {
+    /// Accept addresses of any byte length
+    #[arg(long)]
+    lenient: bool,
+
+    /// Use EIP-55 mixed-case checksums for ETH addresses
+    #[arg(long)]
+    checksum: bool,
+
+    /// Chain ID for EIP-1191 checksums, implies --checksum
+    #[arg(long)]
+    chain_id: Option<u64>,
+}
+
+impl OptionArgs {
+    fn options(&self) -> Options {
+        Options::new()
+            .lenient(self.lenient)
+            .checksum(self.checksum || self.chain_id.is_some())
+            .chain_id(self.chain_id)
+    }
+}
+
+#[derive(Args)]
+struct ConvertArgs {
+    #[command(flatten)]
+    options: OptionArgs,
+
+    #[command(flatten)]
+    input: InputArgs,
+}
+
+#[derive(Clone, Copy, ValueEnum)]
+enum Format {
+    Csv,
+    Jsonl,
+}
+
+#[derive(Clone, Copy, ValueEnum)]
+enum Target {
+    Vlx,
+    Eth,
+}
+
+#[derive(Args)]
+struct BatchArgs {
+    /// Input and output format
+    #[arg(long, value_enum, default_value = "csv")]
+    format: Format,
+
+    /// Name of the column with the addresses
+    #[arg(long)]
+    column: String,
+
+    /// Format to convert the addresses to
+    #[arg(long, value_enum)]
+    to: Target,
+
+    /// Name of the column with the converted address
+    #[arg(long)]
+    output_column: Option<String>,
+
+    /// Name of the column with the validation status
+    #[arg(long)]
+    status_column: Option<String>,
+
+    #[command(flatten)]
+    options: OptionArgs,
+}
+
+fn batch(args: &BatchArgs) -> i32 {
+    let direction = match args.to {
+        Target::Vlx => Direction::ToVlx,
+        Target::Eth => Direction::ToEth,
+    };
+
+    let mut converter =
+        BatchConverter::new(&args.column, direction).options(args.options.options());
+    if let Some(column) = &args.output_column {
+        converter = converter.output_column(column);
+    }
+    if let Some(column) = &args.status_column {
+        converter = converter.status_column(column);
+    }
+
+    let stdin = io::stdin();
+    let stdout = BufWriter::new(io::stdout());
+    let result = match args.format {
+        Format::Csv => converter.convert_csv(stdin.lock(), stdout),
+        Format::Jsonl => converter.convert_json_lines(stdin.lock(), stdout),
+    };
+
+    match result {
+        Ok(report) => {
+            eprintln!("{} rows, {} errors", report.rows, report.errors.len());
+            report
+                .errors
+                .first()
+                .map_or(0, |row| row_exit_code(&row.error))
+        }
+        Err(err) => {
+            eprintln!("velas-address: {}", err);
+            1
+        }
+    }
+}
+
+#[derive(Args)]
+struct VanityArgs {
+    /// Start of the address, including the leading V
+    #[arg(long, default_value = "")]
+    prefix: String,
+
+    /// End of the address
+    #[arg(long, default_value = "")]
+    suffix: String,
+
+    /// Regex the whole address has to match
+    #[cfg(feature = "vanity-regex")]
+    #[arg(long)]
+    regex: Option<String>,
+
+    /// Match prefix and suffix case-insensitively
+    #[arg(long, short = 'i')]
+    ignore_case: bool,
+
+    /// Number of threads, all cores if omitted
+    #[arg(long)]
+    threads: Option<usize>,
+}
+
+/// Vanity search result, borrowing the private key instead of copying it
+#[derive(Serialize)]
+struct VanityOutput<'a> {
+    vlx: &'a str,
+    eth: &'a str,
+    private_key: &'a str,
+    attempts: u64,
+}
+
+fn vanity(args: &VanityArgs, json: bool) -> i32 {
+    let mut search = VanitySearch::new()
+        .prefix(&args.prefix)
+        .suffix(&args.suffix)
+        .case_sensitive(!args.ignore_case);
+    if let Some(threads) = args.threads {
+        search = search.threads(threads);
+    }
+    #[cfg(feature = "vanity-regex")]
+    if let Some(regex) = &args.regex {
+        match regex::Regex::new(regex) {
+            Ok(regex) => search = search.regex(regex),
+            Err(err) => {
+                eprintln!("velas-address: {}", err);
+                return 1;
+            }
+        }
+    }
+
+    if let Some(difficulty) = search.difficulty() {
+        eprintln!("difficulty: {:.0} keys on average", difficulty);
+    }
+
+    let result = search.search_with_progress(Duration::from_secs(1), |progress| {
+        match progress.probability() {
+            Some(probability) => eprintln!(
+                "{} keys, {:.0} keys/s, {:.1}% probability",
+                progress.attempts,
+                progress.rate(),
+                probability * 100.0
+            ),
+            None => eprintln!("{} keys, {:.0} keys/s", progress.attempts, progress.rate()),
+        }
+    });
+
+    let found = match result {
+        Ok(found) => found,
+        Err(err) => {
+            eprintln!("velas-address: {}", err);
+            return 1;
+        }
+    };
+    eprintln!("found after {} keys", found.attempts);
+
+    let private_key = Zeroizing::new(hex::encode(found.private_key.as_bytes()));
+    if json {
+        let (vlx, eth) = (found.vlx.to_string(), found.eth.to_checksum());
+        let output = VanityOutput {
+            vlx: &vlx,
+            eth: &eth,
+            private_key: &private_key,
+            attempts: found.attempts,
+        };
+
+        let stdout = io::stdout();
+        let mut stdout = stdout.lock();
+        if let Err(err) = serde_json::to_writer(&mut stdout, &output)
+            .map_err(io::Error::from)
+            .and_then(|_| writeln!(stdout))
+        {
+            eprintln!("velas-address: {}", err);
+            return 1;
+        }
+    } else {
+        println!(
+            "{}\t{}\t{}",
+            found.vlx,
+            found.eth.to_checksum(),
+            *private_key
+        );
+    }
+
+    0
+}
+
+fn exit_code(err: &AddressError) -> i32 {
+    match err {
+        AddressError::MissingPrefix => 10,
+        AddressError::InvalidLength { .. } => 11,
+        AddressError::InvalidCharacter { .. } => 12,
+        AddressError::ChecksumMismatch { .. } => 13,
+        AddressError::NonCanonicalPadding => 14,
+        AddressError::InvalidChecksumCase { .. } => 15,
+        AddressError::InvalidKey => 16,
+        AddressError::InvalidMnemonic => 17,
+        AddressError::InvalidDerivationPath => 18,
+        AddressError::NonCanonical => 19,
+        AddressError::OddLength => 22,
+        _ => 1,
+    }
+}
+
+fn row_exit_code(err: &RowErrorKind) -> i32 {
+    match err {
+        RowErrorKind::Address(err) => exit_code(err),
+        RowErrorKind::MissingField => 20,
+        RowErrorKind::Malformed(_) => 21,
+    }
+}
+
+fn kind_name(kind: &AddressKind) -> &'static str {
+    match kind {
+        AddressKind::Eth(_) => "eth",
+        AddressKind::Vlx(_) => "vlx",
+        AddressKind::Native(_) => "native",
+    }
+}
+
+fn addresses(input: &InputArgs) -> Box<dyn Iterator<Item = String> + '_> {
+    if !input.addresses.is_empty() {
+        return Box::new(input.addresses.iter().cloned());
+    }
+
+    Box::new(
+        io::stdin()
+            .lock()
+            .lines()
+            .map(|line| {
+                line.unwrap_or_else(|err| {
+                    eprintln!("velas-address: {}", err);
+                    process::exit(1);
+                })
+            })
+            .map(|line| line.trim().to_string())
+            .filter(|line| !line.is_empty()),
+    )
+}
+
+fn main() {
+    let cli = Cli::parse();
+    let mut code = 0;
+
+    let input = match &cli.command {
+        Command::ToVlx(args) | Command::ToEth(args) => &args.input,
+        Command::Validate(args) | Command::Detect(args) => args,
+        Command::Batch(args) => process::exit(batch(args)),
+        Command::Vanity(args) => process::exit(vanity(args, cli.json)),
+    };
+
+    for address in addresses(input) {
+        let result = match &cli.command {
+            Command::ToVlx(args) => eth_to_vlx_with(&address, args.options.options())
+                .map(|output| (json!({ "input": address, "output": output }), output)),
+            Command::ToEth(args) => vlx_to_eth_with(&address, args.options.options())
+                .map(|output| (json!({ "input": address, "output": output }), output)),
+            Command::Validate(_) => detect(&address).map(|kind| {
+                let json = json!({ "input": address, "valid": true, "kind": kind_name(&kind) });
+                (json, "valid".to_string())
+            }),
+            Command::Detect(_) => detect(&address).map(|kind| {
+                let payload = hex::encode(kind.as_bytes());
+                let json =
+                    json!({ "input": address, "kind": kind_name(&kind), "payload": payload });
+                (json, format!("{}\t{}", kind_name(&kind), payload))
+            }),
+            Command::Batch(_) | Command::Vanity(_) => unreachable!(),
+        };
+
+        match result {
+            Ok((json, text)) => {
+                if cli.json {
+                    println!("{}", json);
+                } else {
+                    println!("{}", text);
+                }
+            }
+            Err(err) => {
+                if cli.json {
+                    let mut json = json!({ "input": address, "error": err.to_string(), "code": exit_code(&err) });
+                    if let Command::Validate(_) = cli.command {
+                        json["valid"] = false.into();
+                    }
+                    println!("{}", json);
+                } else {
+                    eprintln!("{}: {}", address, err);
+                }
+
+                if code == 0 {
+                    code = exit_code(&err);
+                }
+            }
+        }
+    }
+
+    process::exit(code);
+}
@@ -0,0 +1,256 @@
+use crate::{base58, find_invalid_char, hex_sha256d, AddressError};
+use alloc::string::{String, ToString};
+use alloc::vec;
+use alloc::vec::Vec;
+
+/// Payload and checksum bytes that are encoded and decoded without allocating
+const STACK_LEN: usize = 64;
+
+/// The first `len` bytes of `stack`, or of `heap` if `stack` is too short
+fn scratch<'a>(stack: &'a mut [u8], heap: &'a mut Vec<u8>, len: usize) -> &'a mut [u8] {
+    if len <= stack.len() {
+        &mut stack[..len]
+    } else {
+        heap.resize(len, 0);
+        heap
+    }
+}
+
+/// Base58 address format with a prefix and a checksum appended to the payload,
+/// of which the Velas `V` format is one instance
+///
+/// Forks and test networks with the same scheme only differ in some of the
+/// parameters, so start from [`AddressCodec::VELAS`] and change those.
+///
+/// ```rust
+/// use velas_address_rust::*;
+///
+/// let mut payload = [0u8; 20];
+/// hex::decode_to_slice("32be343b94f860124dc4fee278fdcbd38c102d88", &mut payload).unwrap();
+///
+/// let vlx = AddressCodec::VELAS.encode(&payload).unwrap();
+/// assert_eq!(vlx, "V5dJeCa7bmkqmZF53TqjRbnB4fG6hxuu4f");
+/// assert_eq!(AddressCodec::VELAS.decode(&vlx).unwrap(), payload);
+///
+/// const TESTNET: AddressCodec = AddressCodec::VELAS.prefix("T");
+/// let address = TESTNET.encode(&payload).unwrap();
+/// assert_eq!(address, "T5dJeCa7bmkqmZF53TqjRbnB4fG6hxuu4f");
+/// assert_eq!(AddressCodec::VELAS.decode(&address), Err(AddressError::MissingPrefix));
+/// ```
+///
+#[derive(Clone, Copy, Debug)]
+pub struct AddressCodec {
+    prefix: &'static str,
+    alphabet: &'static [u8; 58],
+    checksum: fn(&[u8]) -> [u8; 32],
+    checksum_len: usize,
+    payload_len: usize,
+    padding: usize,
+}
+
+impl AddressCodec {
+    /// Base58 alphabet of Bitcoin, used by Velas
+    pub const BITCOIN_ALPHABET: &'static [u8; 58] = base58::ALPHABET;
+
+    /// The Velas `V` format: 20 bytes of payload and 4 bytes of the double
+    /// SHA-256 of the lowercase hex payload, padded with '1' to 33 digits
+    pub const VELAS: AddressCodec = AddressCodec {
+        prefix: "V",
+        alphabet: base58::ALPHABET,
+        checksum: hex_sha256d,
+        checksum_len: 4,
+        payload_len: 20,
+        padding: 33,
+    };
+
+    /// Text in front of the base58 digits
+    pub const fn prefix(mut self, prefix: &'static str) -> Self {
+        self.prefix = prefix;
+        self
+    }
+
+    /// Base58 digits from 0 to 57
+    ///
+    /// Panics if a character is not ASCII or appears more than once.
+    pub const fn alphabet(mut self, alphabet: &'static [u8; 58]) -> Self {
+        let mut i = 0;
+        while i < alphabet.len() {
+            assert!(alphabet[i].is_ascii(), "alphabet must be ASCII");
+            let mut j = i + 1;
+            while j < alphabet.len() {
+                assert!(alphabet[i] != alphabet[j], "alphabet has duplicates");
+                j += 1;
+            }
+            i += 1;
+        }
+
+        self.alphabet = alphabet;
+        self
+    }
+
+    /// Hash of the payload whose first `checksum_len` bytes are appended to it
+    pub const fn checksum(mut self, checksum: fn(&[u8]) -> [u8; 32]) -> Self {
+        self.checksum = checksum;
+        self
+    }
+
+    /// Number of checksum bytes
+    ///
+    /// Panics if `checksum_len` is above 32.
+    pub const fn checksum_len(mut self, checksum_len: usize) -> Self {
+        assert!(checksum_len <= 32, "checksum is at most 32 bytes");
+        self.checksum_len = checksum_len;
+        self
+    }
+
+    /// Number of payload bytes
+    pub const fn payload_len(mut self, payload_len: usize) -> Self {
+        self.payload_len = payload_len;
+        self
+    }
+
+    /// Minimum number of digits, shorter encodings are padded with the
+    /// zero digit of the alphabet
+    pub const fn padding(mut self, padding: usize) -> Self {
+        self.padding = padding;
+        self
+    }
+
+    /// Maximum length of an address with `len` bytes of payload
+    fn encoded_len(&self, len: usize) -> usize {
+        let digits = base58::encoded_len(len + self.checksum_len);
+        self.prefix.len() + digits.max(self.padding)
+    }
+
+    /// Write the address of `payload` into the start of `buf` and return it
+    ///
+    /// Payloads of any length are encoded, `buf` must hold the address.
+    pub(crate) fn encode_into<'a>(&self, payload: &[u8], buf: &'a mut [u8]) -> &'a str {
+        let len = payload.len() + self.checksum_len;
+        let (mut bytes, mut bytes_heap) = ([0u8; STACK_LEN], Vec::new());
+        let bytes = scratch(&mut bytes, &mut bytes_heap, len);
+        let (mut digits, mut digits_heap) = ([0u8; base58::encoded_len(STACK_LEN)], Vec::new());
+        let digits = scratch(&mut digits, &mut digits_heap, base58::encoded_len(len));
+
+        bytes[..payload.len()].copy_from_slice(payload);
+        bytes[payload.len()..].copy_from_slice(&(self.checksum)(payload)[..self.checksum_len]);
+        let digits_len = base58::encode_into(bytes, self.alphabet, digits);
+        let digits = &digits[..digits_len];
+
+        let (prefix, rest) = buf.split_at_mut(self.prefix.len());
+        prefix.copy_from_slice(self.prefix.as_bytes());
+        let padding = self.padding.saturating_sub(digits.len());
+        rest[..padding]
+            .iter_mut()
+            .for_each(|ch| *ch = self.alphabet[0]);
+        rest[padding..padding + digits.len()].copy_from_slice(digits);
+
+        let len = prefix.len() + padding + digits.len();
+        core::str::from_utf8(&buf[..len]).unwrap()
+    }
+
+    /// Decode an address into the start of `payload` and return the payload
+    /// length
+    ///
+    /// Addresses of legacy payloads shorter than `payload_len` bytes decode to
+    /// their length, `payload` must hold `payload_len` bytes.
+    pub(crate) fn decode_into(
+        &self,
+        address: &str,
+        payload: &mut [u8],
+    ) -> Result<usize, AddressError> {
+        let digits = address
+            .strip_prefix(self.prefix)
+            .ok_or(AddressError::MissingPrefix)?;
+
+        let offset = self.prefix.chars().count();
+        if let Some(err) = find_invalid_char(digits, self.alphabet, offset) {
+            return Err(err);
+        }
+
+        // Leading zero digits are padding, only the rest has to fit into
+        // the payload and the checksum
+        let value = digits.trim_start_matches(self.alphabet[0] as char);
+        let zeros = digits.len() - value.len();
+
+        let len = self.payload_len + self.checksum_len;
+        let (mut bytes, mut bytes_heap) = ([0u8; STACK_LEN], Vec::new());
+        let bytes = scratch(&mut bytes, &mut bytes_heap, len);
+        let decoded = match base58::decode_into(value.as_bytes(), self.alphabet, bytes) {
+            Some(decoded) => decoded,
+            None => {
+                let found = base58::min_decoded_len(digits.as_bytes(), self.alphabet);
+                return Err(AddressError::InvalidLength {
+                    expected: self.payload_len,
+                    found: found.max(zeros + len + 1) - self.checksum_len,
+                });
+            }
+        };
+
+        let total = zeros + decoded;
+        if total <= self.checksum_len {
+            return Err(AddressError::InvalidLength {
+                expected: self.payload_len,
+                found: 0,
+            });
+        }
+
+        bytes.copy_within(..decoded, len - decoded);
+        bytes[..len - decoded].iter_mut().for_each(|byte| *byte = 0);
+
+        let (padded, found) = bytes.split_at(self.payload_len);
+        let payload_len = total.min(len) - self.checksum_len;
+        let clear_payload = &padded[self.payload_len - payload_len..];
+        let expected = &(self.checksum)(clear_payload)[..self.checksum_len];
+
+        if expected != found {
+            let (mut expected_head, mut found_head) = ([0u8; 4], [0u8; 4]);
+            let head = self.checksum_len.min(4);
+            expected_head[..head].copy_from_slice(&expected[..head]);
+            found_head[..head].copy_from_slice(&found[..head]);
+
+            return Err(AddressError::ChecksumMismatch {
+                expected: expected_head,
+                found: found_head,
+            });
+        }
+
+        payload[..payload_len].copy_from_slice(clear_payload);
+        Ok(payload_len)
+    }
+
+    /// Encode a payload of `payload_len` bytes
+    pub fn encode(&self, payload: &[u8]) -> Result<String, AddressError> {
+        if payload.len() != self.payload_len {
+            return Err(AddressError::InvalidLength {
+                expected: self.payload_len,
+                found: payload.len(),
+            });
+        }
+
+        let mut buf = vec![0u8; self.encoded_len(payload.len())];
+        Ok(self.encode_into(payload, &mut buf).to_string())
+    }
+
+    /// Decode an address into its `payload_len` bytes
+    ///
+    /// The address has to be exactly what [`encode`](Self::encode) produces.
+    /// Checksum mismatches report the first 4 bytes of the checksums.
+    pub fn decode(&self, address: &str) -> Result<Vec<u8>, AddressError> {
+        let mut payload = vec![0u8; self.payload_len];
+        let len = self.decode_into(address, &mut payload)?;
+
+        if len != self.payload_len {
+            return Err(AddressError::InvalidLength {
+                expected: self.payload_len,
+                found: len,
+            });
+        }
+
+        if self.encode(&payload)? != address {
+            return Err(AddressError::NonCanonical);
+        }
+
+        Ok(payload)
+    }
+}
@@ -0,0 +1,88 @@
+use crate::detect::parse_evm;
+use crate::{keccak256, AddressError, EthAddress, VlxAddress};
+use core::convert::TryFrom;
+
+fn from_hash(hash: &[u8; 32]) -> (EthAddress, VlxAddress) {
+    let eth = EthAddress::try_from(&hash[12..]).unwrap();
+    (eth, VlxAddress::from(eth))
+}
+
+/// Predict the address of a contract deployed with `CREATE`
+///
+/// The address is the Keccak-256 hash of the RLP encoded list of `sender`
+/// and `nonce`. `sender` can be given in `0x` or `V` format.
+///
+/// ```rust
+/// use velas_address_rust::*;
+///
+/// let (eth, vlx) = create_address("0x6ac7ea33f8831ea9dcc53393aaa88b25a785dbf0", 0).unwrap();
+/// assert_eq!(eth.to_string(), "0xcd234a471b72ba2f1ccf0a70fcaba648a5eecd8d");
+/// assert_eq!(vlx.to_string(), eth_to_vlx("0xcd234a471b72ba2f1ccf0a70fcaba648a5eecd8d").unwrap());
+/// ```
+///
+pub fn create_address(sender: &str, nonce: u64) -> Result<(EthAddress, VlxAddress), AddressError> {
+    let sender = parse_evm(sender)?;
+
+    let nonce_bytes = nonce.to_be_bytes();
+    let nonce_bytes = &nonce_bytes[nonce.leading_zeros() as usize / 8..];
+
+    // RLP of [sender, nonce], every item is shorter than 56 bytes
+    let mut rlp = [0u8; 31];
+    rlp[1] = 0x80 + 20;
+    rlp[2..22].copy_from_slice(sender.as_bytes());
+    let len = match nonce_bytes {
+        [byte] if *byte < 0x80 => {
+            rlp[22] = *byte;
+            23
+        }
+        _ => {
+            rlp[22] = 0x80 + nonce_bytes.len() as u8;
+            rlp[23..23 + nonce_bytes.len()].copy_from_slice(nonce_bytes);
+            23 + nonce_bytes.len()
+        }
+    };
+    rlp[0] = 0xc0 + (len - 1) as u8;
+
+    Ok(from_hash(&keccak256(&rlp[..len])))
+}
+
+/// Predict the address of a contract deployed with `CREATE2` as of EIP-1014
+///
+/// `deployer` can be given in `0x` or `V` format, `init_code_hash` is the
+/// Keccak-256 hash of the contract creation code.
+///
+/// ```rust
+/// use velas_address_rust::*;
+///
+/// // Keccak-256 of the init code 0x00
+/// let mut init_code_hash = [0u8; 32];
+/// hex::decode_to_slice(
+///     "bc36789e7a1e281436464229828f817d6612f7b477d66591ff96a9e064bcc98a",
+///     &mut init_code_hash,
+/// )
+/// .unwrap();
+///
+/// let (eth, _) = create2_address(
+///     "0xdeadbeef00000000000000000000000000000000",
+///     &[0u8; 32],
+///     &init_code_hash,
+/// )
+/// .unwrap();
+/// assert_eq!(eth.to_checksum(), "0xB928f69Bb1D91Cd65274e3c79d8986362984fDA3");
+/// ```
+///
+pub fn create2_address(
+    deployer: &str,
+    salt: &[u8; 32],
+    init_code_hash: &[u8; 32],
+) -> Result<(EthAddress, VlxAddress), AddressError> {
+    let deployer = parse_evm(deployer)?;
+
+    let mut bytes = [0u8; 85];
+    bytes[0] = 0xff;
+    bytes[1..21].copy_from_slice(deployer.as_bytes());
+    bytes[21..53].copy_from_slice(salt);
+    bytes[53..].copy_from_slice(init_code_hash);
+
+    Ok(from_hash(&keccak256(&bytes)))
+}
@@ -0,0 +1,72 @@
+use crate::{AddressError, EthAddress, NativeAddress, VlxAddress};
+use core::fmt;
+
+/// Address format detected by [`detect`] together with the decoded address
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum AddressKind {
+    /// EVM address in `0x` hex format
+    Eth(EthAddress),
+    /// EVM address in Velas `V` format
+    Vlx(VlxAddress),
+    /// Native ed25519 address in plain base58
+    Native(NativeAddress),
+}
+
+impl AddressKind {
+    /// Decoded payload, 20 bytes for EVM addresses and 32 bytes for native addresses
+    pub fn as_bytes(&self) -> &[u8] {
+        match self {
+            AddressKind::Eth(address) => address.as_bytes(),
+            AddressKind::Vlx(address) => address.as_bytes(),
+            AddressKind::Native(address) => address.as_bytes(),
+        }
+    }
+}
+
+impl fmt::Display for AddressKind {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            AddressKind::Eth(address) => address.fmt(f),
+            AddressKind::Vlx(address) => address.fmt(f),
+            AddressKind::Native(address) => address.fmt(f),
+        }
+    }
+}
+
+/// Detect the format of an address and decode it
+///
+/// ```rust
+/// use velas_address_rust::*;
+///
+/// match detect("V5dJeCa7bmkqmZF53TqjRbnB4fG6hxuu4f").unwrap() {
+///     AddressKind::Vlx(vlx) => assert_eq!(EthAddress::from(vlx).to_string(), "0x32be343b94f860124dc4fee278fdcbd38c102d88"),
+///     _ => unreachable!(),
+/// }
+///
+/// assert!(matches!(detect("0x32Be343B94f860124dC4fEe278FDCBD38C102D88"), Ok(AddressKind::Eth(_))));
+/// assert!(matches!(detect("Vote111111111111111111111111111111111111111"), Ok(AddressKind::Native(_))));
+/// ```
+///
+pub fn detect(address: &str) -> Result<AddressKind, AddressError> {
+    if address.starts_with("0x") {
+        return address.parse().map(AddressKind::Eth);
+    }
+
+    if address.starts_with('V') {
+        return match address.parse() {
+            Ok(vlx) => Ok(AddressKind::Vlx(vlx)),
+            Err(err) => address.parse().map(AddressKind::Native).map_err(|_| err),
+        };
+    }
+
+    address.parse().map(AddressKind::Native)
+}
+
+/// Parse an EVM address in either `0x` or `V` format
+pub(crate) fn parse_evm(address: &str) -> Result<EthAddress, AddressError> {
+    if address.starts_with("0x") {
+        address.parse()
+    } else {
+        address.parse::<VlxAddress>().map(EthAddress::from)
+    }
+}
@@ -0,0 +1,56 @@
+use crate::{keccak256, AddressError};
+use alloc::format;
+use alloc::string::String;
+
+/// Mixed-case EIP-55 form of a lowercase hex address without `0x` prefix,
+/// or EIP-1191 form if a chain ID is given
+pub(crate) fn to_checksum(clear_addr: &str, chain_id: Option<u64>) -> String {
+    let hash = match chain_id {
+        Some(chain_id) => keccak256(format!("{}0x{}", chain_id, clear_addr).as_bytes()),
+        None => keccak256(clear_addr.as_bytes()),
+    };
+
+    clear_addr
+        .chars()
+        .enumerate()
+        .map(|(i, ch)| {
+            let nibble = (hash[i / 2] >> (if i % 2 == 0 { 4 } else { 0 })) & 0x0f;
+            if nibble >= 8 {
+                ch.to_ascii_uppercase()
+            } else {
+                ch
+            }
+        })
+        .collect()
+}
+
+/// Verify the EIP-55 (or EIP-1191 if a chain ID is given) checksum of a hex address
+/// without `0x` prefix
+///
+/// All-lowercase and all-uppercase addresses carry no checksum and are always accepted.
+pub(crate) fn verify_checksum(
+    clear_addr: &str,
+    chain_id: Option<u64>,
+    offset: usize,
+) -> Result<(), AddressError> {
+    let has_lower = clear_addr.chars().any(|ch| ch.is_ascii_lowercase());
+    let has_upper = clear_addr.chars().any(|ch| ch.is_ascii_uppercase());
+
+    if !(has_lower && has_upper) {
+        return Ok(());
+    }
+
+    let expected = to_checksum(&clear_addr.to_lowercase(), chain_id);
+
+    match clear_addr
+        .chars()
+        .zip(expected.chars())
+        .position(|(found, expected)| found != expected)
+    {
+        Some(position) => Err(AddressError::InvalidChecksumCase {
+            position: position + offset,
+            ch: clear_addr[position..].chars().next().unwrap(),
+        }),
+        None => Ok(()),
+    }
+}
@@ -0,0 +1,70 @@
+use core::fmt;
+
+/// Error returned when en/decoding an address fails
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum AddressError {
+    /// The address does not start with the expected prefix (`0x` or `V`)
+    MissingPrefix,
+    /// The address payload or key does not have the expected length in bytes
+    InvalidLength { expected: usize, found: usize },
+    /// The hex payload of a `0x` address has an odd number of digits
+    OddLength,
+    /// The character at `position` is not allowed in this address format
+    InvalidCharacter { position: usize, ch: char },
+    /// The checksum stored in the address does not match the computed one
+    ChecksumMismatch { expected: [u8; 4], found: [u8; 4] },
+    /// The bytes in front of the 20-byte address are not zero padding
+    NonCanonicalPadding,
+    /// The character at `position` has the wrong case for the EIP-55 checksum
+    InvalidChecksumCase { position: usize, ch: char },
+    /// The bytes are not a valid secp256k1 public or private key
+    InvalidKey,
+    /// The phrase is not a valid BIP-39 mnemonic
+    InvalidMnemonic,
+    /// The BIP-32 derivation path is malformed or cannot be derived from this key
+    InvalidDerivationPath,
+    /// The `V` address is valid but not encoded the way `eth_to_vlx` encodes it,
+    /// e.g. with extra leading '1's, see [`canonicalize`](crate::canonicalize)
+    NonCanonical,
+}
+
+impl fmt::Display for AddressError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            AddressError::MissingPrefix => write!(f, "Invalid address: missing prefix"),
+            AddressError::InvalidLength { expected, found } => write!(
+                f,
+                "Invalid address: expected {} bytes, found {}",
+                expected, found
+            ),
+            AddressError::InvalidCharacter { position, ch } => write!(
+                f,
+                "Invalid address: invalid character {:?} at position {}",
+                ch, position
+            ),
+            AddressError::ChecksumMismatch { expected, found } => write!(
+                f,
+                "Invalid checksum: expected {}, found {}",
+                hex::encode(expected),
+                hex::encode(found)
+            ),
+            AddressError::NonCanonicalPadding => {
+                write!(f, "Invalid address: non-canonical padding")
+            }
+            AddressError::InvalidChecksumCase { position, ch } => write!(
+                f,
+                "Invalid checksum: wrong case of {:?} at position {}",
+                ch, position
+            ),
+            AddressError::InvalidKey => write!(f, "Invalid key"),
+            AddressError::InvalidMnemonic => write!(f, "Invalid mnemonic"),
+            AddressError::InvalidDerivationPath => write!(f, "Invalid derivation path"),
+            AddressError::OddLength => write!(f, "Invalid address: odd number of hex digits"),
+            AddressError::NonCanonical => write!(f, "Invalid address: non-canonical encoding"),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for AddressError {}
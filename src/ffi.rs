@@ -0,0 +1,174 @@
+//! C bindings, the header is `include/velas_address.h`
+//!
+//! All functions take NUL-terminated UTF-8 strings and return `VELAS_OK` or
+//! one of the `VELAS_ERROR_*` codes. Converted addresses are written NUL-terminated
+//! into a caller provided buffer of `VELAS_ADDRESS_MAX_LEN` bytes, which is enough
+//! for every address unless `VELAS_FLAG_LENIENT` is set.
+
+use crate::{AddressError, Options};
+use std::ffi::CStr;
+use std::os::raw::{c_char, c_int};
+
+/// Accept addresses of any length
+pub const VELAS_FLAG_LENIENT: u32 = 1;
+/// Verify (`velas_eth_to_vlx`) or produce (`velas_vlx_to_eth`) the EIP-55 checksum
+pub const VELAS_FLAG_CHECKSUM: u32 = 2;
+
+/// Buffer size that fits a converted 20-byte address including the NUL terminator
+pub const VELAS_ADDRESS_MAX_LEN: usize = 64;
+
+pub const VELAS_OK: c_int = 0;
+pub const VELAS_ERROR_MISSING_PREFIX: c_int = 1;
+pub const VELAS_ERROR_INVALID_LENGTH: c_int = 2;
+pub const VELAS_ERROR_INVALID_CHARACTER: c_int = 3;
+pub const VELAS_ERROR_CHECKSUM_MISMATCH: c_int = 4;
+pub const VELAS_ERROR_NON_CANONICAL_PADDING: c_int = 5;
+pub const VELAS_ERROR_INVALID_CHECKSUM_CASE: c_int = 6;
+pub const VELAS_ERROR_INVALID_KEY: c_int = 7;
+pub const VELAS_ERROR_INVALID_MNEMONIC: c_int = 8;
+pub const VELAS_ERROR_INVALID_DERIVATION_PATH: c_int = 9;
+pub const VELAS_ERROR_NON_CANONICAL: c_int = 10;
+pub const VELAS_ERROR_ODD_LENGTH: c_int = 11;
+/// A pointer argument is NULL
+pub const VELAS_ERROR_NULL_POINTER: c_int = -1;
+/// The input is not valid UTF-8
+pub const VELAS_ERROR_INVALID_UTF8: c_int = -2;
+/// The output buffer is too small for the result
+pub const VELAS_ERROR_BUFFER_TOO_SMALL: c_int = -3;
+
+fn error_code(err: AddressError) -> c_int {
+    match err {
+        AddressError::MissingPrefix => VELAS_ERROR_MISSING_PREFIX,
+        AddressError::InvalidLength { .. } => VELAS_ERROR_INVALID_LENGTH,
+        AddressError::InvalidCharacter { .. } => VELAS_ERROR_INVALID_CHARACTER,
+        AddressError::ChecksumMismatch { .. } => VELAS_ERROR_CHECKSUM_MISMATCH,
+        AddressError::NonCanonicalPadding => VELAS_ERROR_NON_CANONICAL_PADDING,
+        AddressError::InvalidChecksumCase { .. } => VELAS_ERROR_INVALID_CHECKSUM_CASE,
+        AddressError::InvalidKey => VELAS_ERROR_INVALID_KEY,
+        AddressError::InvalidMnemonic => VELAS_ERROR_INVALID_MNEMONIC,
+        AddressError::InvalidDerivationPath => VELAS_ERROR_INVALID_DERIVATION_PATH,
+        AddressError::NonCanonical => VELAS_ERROR_NON_CANONICAL,
+        AddressError::OddLength => VELAS_ERROR_ODD_LENGTH,
+    }
+}
+
+fn options(flags: u32) -> Options {
+    Options::new()
+        .lenient(flags & VELAS_FLAG_LENIENT != 0)
+        .checksum(flags & VELAS_FLAG_CHECKSUM != 0)
+}
+
+unsafe fn read_str<'a>(address: *const c_char) -> Result<&'a str, c_int> {
+    if address.is_null() {
+        return Err(VELAS_ERROR_NULL_POINTER);
+    }
+
+    CStr::from_ptr(address)
+        .to_str()
+        .map_err(|_| VELAS_ERROR_INVALID_UTF8)
+}
+
+unsafe fn convert(
+    address: *const c_char,
+    out: *mut c_char,
+    out_len: usize,
+    f: impl FnOnce(&str) -> Result<String, AddressError>,
+) -> c_int {
+    if out.is_null() {
+        return VELAS_ERROR_NULL_POINTER;
+    }
+
+    let address = match read_str(address) {
+        Ok(address) => address,
+        Err(code) => return code,
+    };
+
+    let converted = match f(address) {
+        Ok(converted) => converted,
+        Err(err) => return error_code(err),
+    };
+
+    if converted.len() >= out_len {
+        return VELAS_ERROR_BUFFER_TOO_SMALL;
+    }
+
+    let out = std::slice::from_raw_parts_mut(out as *mut u8, out_len);
+    out[..converted.len()].copy_from_slice(converted.as_bytes());
+    out[converted.len()] = 0;
+
+    VELAS_OK
+}
+
+/// Convert ETH address to VLX address
+///
+/// # Safety
+///
+/// `address` must be NULL or a NUL-terminated string, `out` must be NULL or
+/// point to at least `out_len` writable bytes.
+#[no_mangle]
+pub unsafe extern "C" fn velas_eth_to_vlx(
+    address: *const c_char,
+    flags: u32,
+    out: *mut c_char,
+    out_len: usize,
+) -> c_int {
+    convert(address, out, out_len, |address| {
+        crate::eth_to_vlx_with(address, options(flags))
+    })
+}
+
+/// Convert VLX address to ETH address
+///
+/// # Safety
+///
+/// `address` must be NULL or a NUL-terminated string, `out` must be NULL or
+/// point to at least `out_len` writable bytes.
+#[no_mangle]
+pub unsafe extern "C" fn velas_vlx_to_eth(
+    address: *const c_char,
+    flags: u32,
+    out: *mut c_char,
+    out_len: usize,
+) -> c_int {
+    convert(address, out, out_len, |address| {
+        crate::vlx_to_eth_with(address, options(flags))
+    })
+}
+
+/// Check that an address is valid in any supported format
+///
+/// # Safety
+///
+/// `address` must be NULL or a NUL-terminated string.
+#[no_mangle]
+pub unsafe extern "C" fn velas_validate(address: *const c_char) -> c_int {
+    match read_str(address) {
+        Ok(address) => crate::detect(address).map_or_else(error_code, |_| VELAS_OK),
+        Err(code) => code,
+    }
+}
+
+/// Static NUL-terminated description of an error code, never NULL
+#[no_mangle]
+pub extern "C" fn velas_error_message(code: c_int) -> *const c_char {
+    let message: &'static [u8] = match code {
+        VELAS_OK => b"OK\0",
+        VELAS_ERROR_MISSING_PREFIX => b"Invalid address: missing prefix\0",
+        VELAS_ERROR_INVALID_LENGTH => b"Invalid address: invalid length\0",
+        VELAS_ERROR_INVALID_CHARACTER => b"Invalid address: invalid character\0",
+        VELAS_ERROR_CHECKSUM_MISMATCH => b"Invalid checksum\0",
+        VELAS_ERROR_NON_CANONICAL_PADDING => b"Invalid address: non-canonical padding\0",
+        VELAS_ERROR_INVALID_CHECKSUM_CASE => b"Invalid checksum: wrong case\0",
+        VELAS_ERROR_INVALID_KEY => b"Invalid key\0",
+        VELAS_ERROR_INVALID_MNEMONIC => b"Invalid mnemonic\0",
+        VELAS_ERROR_INVALID_DERIVATION_PATH => b"Invalid derivation path\0",
+        VELAS_ERROR_NON_CANONICAL => b"Invalid address: non-canonical encoding\0",
+        VELAS_ERROR_ODD_LENGTH => b"Invalid address: odd number of hex digits\0",
+        VELAS_ERROR_NULL_POINTER => b"Null pointer\0",
+        VELAS_ERROR_INVALID_UTF8 => b"Invalid UTF-8\0",
+        VELAS_ERROR_BUFFER_TOO_SMALL => b"Buffer too small\0",
+        _ => b"Unknown error\0",
+    };
+
+    message.as_ptr() as *const c_char
+}
@@ -0,0 +1,105 @@
+use crate::{from_public_key, AddressError, EthAddress, VlxAddress};
+use bip32::{DerivationPath, ExtendedKey, XPrv, XPub};
+use bip39::Mnemonic;
+use std::convert::TryFrom;
+
+/// SLIP-44 coin type of Velas
+pub const VELAS_COIN_TYPE: u32 = 5655640;
+
+/// BIP-44 path of the external Velas address chain, append `/i` for the `i`-th address
+pub const VELAS_DERIVATION_PATH: &str = "m/44'/5655640'/0'/0";
+
+enum Key {
+    Private(XPrv),
+    Public(XPub),
+}
+
+/// BIP-32 HD wallet deriving Velas EVM addresses
+///
+/// ```rust
+/// use velas_address_rust::*;
+///
+/// let phrase = "test test test test test test test test test test test junk";
+/// let wallet = HdWallet::from_mnemonic(phrase, "").unwrap();
+///
+/// for i in 0..3 {
+///     let (eth, vlx) = wallet.derive(&format!("{}/{}", VELAS_DERIVATION_PATH, i)).unwrap();
+///     assert_eq!(vlx.to_string(), eth_to_vlx(&eth.to_string()).unwrap());
+/// }
+/// ```
+///
+pub struct HdWallet {
+    key: Key,
+}
+
+impl HdWallet {
+    /// Wallet from a BIP-39 mnemonic phrase and passphrase
+    pub fn from_mnemonic(phrase: &str, passphrase: &str) -> Result<Self, AddressError> {
+        let mnemonic = Mnemonic::parse(phrase).map_err(|_| AddressError::InvalidMnemonic)?;
+        HdWallet::from_seed(&mnemonic.to_seed(passphrase))
+    }
+
+    /// Wallet from a BIP-32 seed
+    pub fn from_seed(seed: &[u8]) -> Result<Self, AddressError> {
+        let key = XPrv::new(seed).map_err(|_| AddressError::InvalidKey)?;
+        Ok(HdWallet {
+            key: Key::Private(key),
+        })
+    }
+
+    /// Wallet from an `xprv` or `xpub` extended key
+    ///
+    /// Paths passed to [`derive`](HdWallet::derive) are relative to this key, and
+    /// wallets built from an `xpub` can only derive non-hardened children.
+    pub fn from_extended_key(key: &str) -> Result<Self, AddressError> {
+        let key: ExtendedKey = key.parse().map_err(|_| AddressError::InvalidKey)?;
+
+        let key = if key.prefix.is_private() {
+            Key::Private(XPrv::try_from(key).map_err(|_| AddressError::InvalidKey)?)
+        } else {
+            Key::Public(XPub::try_from(key).map_err(|_| AddressError::InvalidKey)?)
+        };
+
+        Ok(HdWallet { key })
+    }
+
+    fn derive_xpub(&self, path: &str) -> Result<XPub, AddressError> {
+        let path: DerivationPath = path
+            .parse()
+            .map_err(|_| AddressError::InvalidDerivationPath)?;
+
+        match &self.key {
+            Key::Private(key) => {
+                let mut key = key.clone();
+                for child in path.iter() {
+                    key = key
+                        .derive_child(child)
+                        .map_err(|_| AddressError::InvalidKey)?;
+                }
+                Ok(key.public_key())
+            }
+            Key::Public(key) => {
+                let mut key = key.clone();
+                for child in path.iter() {
+                    if child.is_hardened() {
+                        return Err(AddressError::InvalidDerivationPath);
+                    }
+                    key = key
+                        .derive_child(child)
+                        .map_err(|_| AddressError::InvalidKey)?;
+                }
+                Ok(key)
+            }
+        }
+    }
+
+    /// Derive ETH and VLX addresses at a path like `m/44'/5655640'/0'/0/0`
+    pub fn derive(&self, path: &str) -> Result<(EthAddress, VlxAddress), AddressError> {
+        from_public_key(&self.derive_xpub(path)?.to_bytes())
+    }
+
+    /// Extended public key at `path`, e.g. the account level `m/44'/5655640'/0'`
+    pub fn to_xpub(&self, path: &str) -> Result<String, AddressError> {
+        Ok(self.derive_xpub(path)?.to_string(bip32::Prefix::XPUB))
+    }
+}
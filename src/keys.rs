@@ -0,0 +1,52 @@
+use crate::{keccak256, AddressError, EthAddress, VlxAddress};
+use core::convert::TryFrom;
+use k256::elliptic_curve::sec1::ToEncodedPoint;
+use k256::{PublicKey, SecretKey};
+
+fn from_key(key: &PublicKey) -> (EthAddress, VlxAddress) {
+    let point = key.to_encoded_point(false);
+    let hash = keccak256(&point.as_bytes()[1..]);
+    let eth = EthAddress::try_from(&hash[12..]).unwrap();
+    (eth, VlxAddress::from(eth))
+}
+
+/// Derive ETH and VLX addresses from a SEC1 encoded secp256k1 public key
+///
+/// Both 33-byte compressed and 65-byte uncompressed keys are accepted.
+///
+/// ```rust
+/// use velas_address_rust::*;
+///
+/// let key = hex::decode("0279be667ef9dcbbac55a06295ce870b07029bfcdb2dce28d959f2815b16f81798").unwrap();
+/// let (eth, vlx) = from_public_key(&key).unwrap();
+/// assert_eq!(eth.to_checksum(), "0x7E5F4552091A69125d5DfCb7b8C2659029395Bdf");
+/// assert_eq!(vlx.to_string(), eth_to_vlx("0x7E5F4552091A69125d5DfCb7b8C2659029395Bdf").unwrap());
+/// ```
+///
+pub fn from_public_key(key: &[u8]) -> Result<(EthAddress, VlxAddress), AddressError> {
+    if key.len() != 33 && key.len() != 65 {
+        return Err(AddressError::InvalidLength {
+            expected: 65,
+            found: key.len(),
+        });
+    }
+
+    let key = PublicKey::from_sec1_bytes(key).map_err(|_| AddressError::InvalidKey)?;
+    Ok(from_key(&key))
+}
+
+/// Derive ETH and VLX addresses from a secp256k1 private key
+///
+/// ```rust
+/// use velas_address_rust::*;
+///
+/// let mut key = [0u8; 32];
+/// key[31] = 1;
+/// let (eth, vlx) = from_private_key(&key).unwrap();
+/// assert_eq!(eth.to_checksum(), "0x7E5F4552091A69125d5DfCb7b8C2659029395Bdf");
+/// ```
+///
+pub fn from_private_key(key: &[u8; 32]) -> Result<(EthAddress, VlxAddress), AddressError> {
+    let key = SecretKey::from_slice(key).map_err(|_| AddressError::InvalidKey)?;
+    Ok(from_key(&key.public_key()))
+}
@@ -10,127 +10,420 @@
 //! let eth_addr = vlx_to_eth(&vlx_addr).unwrap(); // 0x32be343b94f860124dc4fee278fdcbd38c102d88
 //! ```
 //!
-use basex_rs::{BaseX, Decode, Encode, BITCOIN};
+//! Without the default `std` feature the crate is `no_std` and only needs `alloc`.
+//!
+#![cfg_attr(all(not(test), not(feature = "std")), no_std)]
+
+extern crate alloc;
+
+use alloc::string::{String, ToString};
+use alloc::vec::Vec;
+use alloc::{format, vec};
 use bitcoin_hashes::sha256;
-use bitcoin_hashes::Hash;
-use hex;
-use regex::Regex;
-use std::str;
+use bitcoin_hashes::{Hash, HashEngine};
+use tiny_keccak::{Hasher, Keccak};
+
+mod address;
+mod base58;
+#[cfg(feature = "batch")]
+mod batch;
+mod codec;
+mod contract;
+mod detect;
+mod eip55;
+mod error;
+#[cfg(feature = "ffi")]
+pub mod ffi;
+#[cfg(feature = "hd")]
+mod hd;
+#[cfg(feature = "keys")]
+mod keys;
+mod options;
+#[cfg(feature = "python")]
+mod python;
+#[cfg(feature = "serde")]
+pub mod serde;
+mod suggest;
+#[cfg(feature = "vanity")]
+mod vanity;
+#[cfg(feature = "wasm")]
+pub mod wasm;
+
+pub use address::{EthAddress, NativeAddress, VlxAddress};
+#[cfg(feature = "batch")]
+pub use batch::{BatchConverter, BatchError, BatchReport, Direction, RowError, RowErrorKind};
+pub use codec::AddressCodec;
+pub use contract::{create2_address, create_address};
+pub use detect::{detect, AddressKind};
+pub use error::AddressError;
+#[cfg(feature = "hd")]
+pub use hd::{HdWallet, VELAS_COIN_TYPE, VELAS_DERIVATION_PATH};
+#[cfg(feature = "keys")]
+pub use keys::{from_private_key, from_public_key};
+pub use options::Options;
+pub use suggest::suggest_corrections;
+#[cfg(feature = "vanity")]
+pub use vanity::{PrivateKey, Progress, VanityError, VanityMatch, VanitySearch};
 
-fn hash_sha256(byte: &[u8]) -> String {
-    format!("{}", sha256::Hash::hash(byte))
+/// Chain ID of the Velas EVM mainnet
+pub const VELAS_MAINNET_CHAIN_ID: u64 = 106;
+
+/// Chain ID of the Velas EVM testnet
+pub const VELAS_TESTNET_CHAIN_ID: u64 = 111;
+
+pub(crate) fn keccak256(bytes: &[u8]) -> [u8; 32] {
+    let mut hash = [0u8; 32];
+    let mut keccak = Keccak::v256();
+    keccak.update(bytes);
+    keccak.finalize(&mut hash);
+    hash
 }
 
-/// Convert ETH address to VLX address
+/// Double SHA-256 of the lowercase hex text of `payload`
+fn hex_sha256d(payload: &[u8]) -> [u8; 32] {
+    let mut hex = [0u8; 64];
+
+    let mut engine = sha256::Hash::engine();
+    for chunk in payload.chunks(32) {
+        let hex = &mut hex[..chunk.len() * 2];
+        hex::encode_to_slice(chunk, hex).unwrap();
+        engine.input(hex);
+    }
+
+    hex::encode_to_slice(sha256::Hash::from_engine(engine), &mut hex).unwrap();
+    sha256::Hash::hash(&hex).into_inner()
+}
+
+/// First 4 bytes of the double SHA-256 of the lowercase hex text of `payload`
+fn checksum(payload: &[u8]) -> [u8; 4] {
+    let hash_big = hex_sha256d(payload);
+
+    let mut checksum = [0u8; 4];
+    checksum.copy_from_slice(&hash_big[0..4]);
+    checksum
+}
+
+/// Checksum of a VLX address, the first 4 bytes of the double SHA-256 of
+/// the lowercase hex text of the 20-byte address
+///
+/// Both hashes are taken over ASCII hex text, not over raw bytes. For the
+/// address `0x32be343b94f860124dc4fee278fdcbd38c102d88` the steps are:
+///
+/// 1. hex text: `32be343b94f860124dc4fee278fdcbd38c102d88`
+/// 2. SHA-256 of the 40 ASCII characters of step 1:
+///    `86a1b0cebb427cb470a5fbde8a918d09fc82d646366de98177c9a3d871bda9b9`
+/// 3. SHA-256 of the 64 ASCII characters of the lowercase hex of step 2:
+///    `6db32c74bc9e5ceafb219765a0578d1ed5f9553ece0b67cf581020e5b92f0684`
+/// 4. checksum, the first 4 bytes of step 3: `6db32c74`
+///
+/// Hashing the raw bytes instead gives `d9a537a9`, which is wrong.
 ///
 /// ```rust
 /// use velas_address_rust::*;
 ///
-/// let eth_addresses = "0x32Be343B94f860124dC4fEe278FDCBD38C102D88";
-/// assert_eq!(eth_to_vlx(eth_addresses).unwrap(), "V5dJeCa7bmkqmZF53TqjRbnB4fG6hxuu4f".to_string())
+/// let mut address = [0u8; 20];
+/// hex::decode_to_slice("32be343b94f860124dc4fee278fdcbd38c102d88", &mut address).unwrap();
+/// assert_eq!(hex::encode(vlx_checksum(&address)), "6db32c74");
+/// ```
+///
+pub fn vlx_checksum(address: &[u8; 20]) -> [u8; 4] {
+    checksum(address)
+}
+
+/// Verify the checksum of a VLX address, see [`vlx_checksum`]
+///
+/// ```rust
+/// use velas_address_rust::*;
+///
+/// let address = [0u8; 20];
+/// assert!(verify_vlx_checksum(&address, &[0x6a, 0x22, 0x54, 0x47]).is_ok());
+/// assert_eq!(
+///     verify_vlx_checksum(&address, &[0, 0, 0, 0]),
+///     Err(AddressError::ChecksumMismatch {
+///         expected: [0x6a, 0x22, 0x54, 0x47],
+///         found: [0, 0, 0, 0]
+///     })
+/// );
 /// ```
 ///
-pub fn eth_to_vlx(address: &str) -> Result<String, &str> {
-    if address.is_empty() {
-        return Err("Invalid address");
+pub fn verify_vlx_checksum(address: &[u8; 20], found: &[u8; 4]) -> Result<(), AddressError> {
+    let expected = vlx_checksum(address);
+
+    if expected != *found {
+        return Err(AddressError::ChecksumMismatch {
+            expected,
+            found: *found,
+        });
     }
 
-    if !address.starts_with("0x") {
-        return Err("Invalid address");
+    Ok(())
+}
+
+pub(crate) fn find_invalid_char(
+    addr: &str,
+    alphabet: &[u8],
+    offset: usize,
+) -> Option<AddressError> {
+    addr.chars()
+        .enumerate()
+        .find(|(_, ch)| !ch.is_ascii() || !alphabet.contains(&(*ch as u8)))
+        .map(|(position, ch)| AddressError::InvalidCharacter {
+            position: position + offset,
+            ch,
+        })
+}
+
+pub(crate) fn decode_base58(addr: &str) -> Result<Vec<u8>, AddressError> {
+    if let Some(err) = find_invalid_char(addr, base58::ALPHABET, 0) {
+        return Err(err);
     }
 
-    let clear_addr = match address.get(2..address.len()) {
-        Some(addr) => addr.to_lowercase(),
-        None => return Err("Invalid address"),
-    };
+    let mut bytes = vec![0u8; addr.len()];
+    let len = base58::decode_into(addr.as_bytes(), base58::ALPHABET, &mut bytes).unwrap();
+    bytes.truncate(len);
+    Ok(bytes)
+}
 
-    let hash_big = hash_sha256(hash_sha256(clear_addr.as_bytes()).as_bytes());
-    let checksum = match hash_big.get(0..8) {
-        Some(hash) => hash,
-        None => return Err("Invalid address"),
-    };
+pub(crate) fn encode_base58(bytes: &[u8]) -> String {
+    let mut encode = vec![0u8; base58::encoded_len(bytes.len())];
+    let len = base58::encode_into(bytes, base58::ALPHABET, &mut encode);
+    encode.truncate(len);
+    String::from_utf8(encode).unwrap()
+}
 
-    let long_address = format!("{}{}", clear_addr, checksum);
+pub(crate) fn strip_hex_prefix(address: &str) -> Result<&str, AddressError> {
+    if !address.starts_with("0x") {
+        return Err(AddressError::MissingPrefix);
+    }
 
-    let bytes = hex::decode(long_address).unwrap().to_vec();
+    let clear_addr = &address[2..];
 
-    let mut encode = BaseX::new(BITCOIN).encode(&bytes);
+    if let Some(err) = find_invalid_char(clear_addr, b"0123456789abcdefABCDEF", 2) {
+        return Err(err);
+    }
+
+    Ok(clear_addr)
+}
 
-    if encode.len() < 33 {
-        encode = format!("{}{}", "1".repeat(33 - encode.len()), encode);
+fn encode(payload: &[u8]) -> String {
+    AddressCodec::VELAS
+        .payload_len(payload.len())
+        .encode(payload)
+        .unwrap()
+}
+
+/// Decode a VLX address into `payload` and return the payload length,
+/// which is at most 20 bytes
+fn decode_into(address: &str, payload: &mut [u8; 20]) -> Result<usize, AddressError> {
+    match AddressCodec::VELAS.decode_into(address, payload) {
+        // Values above 24 bytes have always been reported as non-zero bytes
+        // in front of the address
+        Err(AddressError::InvalidLength { found, .. }) if found > 20 => {
+            Err(AddressError::NonCanonicalPadding)
+        }
+        result => result,
     }
+}
 
-    Ok(format!("V{}", encode))
+fn decode(address: &str) -> Result<Vec<u8>, AddressError> {
+    let mut payload = [0u8; 20];
+    let len = decode_into(address, &mut payload)?;
+    Ok(payload[..len].to_vec())
 }
 
-/// Convert VLX address to ETH address
+/// Encode a 20-byte address as VLX address into `buf` without allocating
 ///
 /// ```rust
 /// use velas_address_rust::*;
 ///
-/// let vlx_addresses = "V5dJeCa7bmkqmZF53TqjRbnB4fG6hxuu4f";
-/// assert_eq!(vlx_to_eth(vlx_addresses).unwrap(), "0x32be343b94f860124dc4fee278fdcbd38c102d88".to_string())
+/// let mut address = [0u8; 20];
+/// hex::decode_to_slice("32be343b94f860124dc4fee278fdcbd38c102d88", &mut address).unwrap();
+///
+/// let mut buf = [0u8; 34];
+/// assert_eq!(encode_vlx_into(&address, &mut buf), "V5dJeCa7bmkqmZF53TqjRbnB4fG6hxuu4f");
+/// ```
+///
+pub fn encode_vlx_into<'a>(address: &[u8; 20], buf: &'a mut [u8; 34]) -> &'a str {
+    AddressCodec::VELAS.encode_into(address, buf)
+}
+
+/// Decode a VLX address into its 20 bytes without allocating
+///
+/// ```rust
+/// use velas_address_rust::*;
+///
+/// let address = decode_vlx("V5dJeCa7bmkqmZF53TqjRbnB4fG6hxuu4f").unwrap();
+/// assert_eq!(hex::encode(address), "32be343b94f860124dc4fee278fdcbd38c102d88");
 /// ```
 ///
-pub fn vlx_to_eth(address: &str) -> Result<String, &str> {
-    if address.is_empty() {
-        return Err("Invalid address");
+pub fn decode_vlx(address: &str) -> Result<[u8; 20], AddressError> {
+    let mut payload = [0u8; 20];
+    let len = decode_into(address, &mut payload)?;
+
+    if len != 20 {
+        return Err(AddressError::InvalidLength {
+            expected: 20,
+            found: len,
+        });
     }
 
-    if !address.starts_with("V") {
-        return Err("Invalid address");
+    // The encoding of a 20-byte payload only differs from the canonical one
+    // in the number of leading '1's, which always pad it to 33 digits
+    if address.len() != 34 {
+        return Err(AddressError::NonCanonical);
+    }
+
+    Ok(payload)
+}
+
+/// Re-encode a VLX address with any number of leading '1's the way
+/// `eth_to_vlx` encodes it
+///
+/// ```rust
+/// use velas_address_rust::*;
+///
+/// let legacy = "V11115dJeCa7bmkqmZF53TqjRbnB4fG6hxuu4f";
+/// assert_eq!(vlx_to_eth(legacy), Err(AddressError::NonCanonical));
+/// assert_eq!(canonicalize(legacy).unwrap(), "V5dJeCa7bmkqmZF53TqjRbnB4fG6hxuu4f");
+/// ```
+///
+pub fn canonicalize(address: &str) -> Result<String, AddressError> {
+    let mut payload = [0u8; 20];
+    let len = decode_into(address, &mut payload)?;
+
+    if len != 20 {
+        return Err(AddressError::InvalidLength {
+            expected: 20,
+            found: len,
+        });
     }
 
-    let clear_addr = match address.get(1..address.len()) {
-        Some(addr) => addr,
-        None => return Err("Invalid address"),
-    };
+    let mut buf = [0u8; 34];
+    Ok(encode_vlx_into(&payload, &mut buf).to_string())
+}
+
+/// Convert ETH address to VLX address
+///
+/// ```rust
+/// use velas_address_rust::*;
+///
+/// let eth_addresses = "0x32Be343B94f860124dC4fEe278FDCBD38C102D88";
+/// assert_eq!(eth_to_vlx(eth_addresses).unwrap(), "V5dJeCa7bmkqmZF53TqjRbnB4fG6hxuu4f".to_string())
+/// ```
+///
+pub fn eth_to_vlx(address: &str) -> Result<String, AddressError> {
+    eth_to_vlx_with(address, Options::default())
+}
+
+/// Convert ETH address to VLX address with the given options
+///
+/// ```rust
+/// use velas_address_rust::*;
+///
+/// let tx_hash = "0x88df016429689c079f3b2f6ad39fa052532c56795b733da78a91ebe6a713944b";
+/// assert!(eth_to_vlx_with(tx_hash, Options::new()).is_err());
+/// assert!(eth_to_vlx_with(tx_hash, Options::new().lenient(true)).is_ok());
+/// ```
+///
+pub fn eth_to_vlx_with(address: &str, options: Options) -> Result<String, AddressError> {
+    if options.lenient {
+        let clear_addr = strip_hex_prefix(address)?;
 
-    let decode_addr = match BaseX::new(BITCOIN).decode(clear_addr.to_string()) {
-        Some(bytes) => bytes,
-        None => return Err("Invalid address"),
-    };
+        if clear_addr.len() % 2 != 0 {
+            return Err(AddressError::OddLength);
+        }
 
-    let hex = hex::encode(decode_addr);
+        if options.checksum {
+            // EIP-55 is only defined for 20-byte addresses
+            if clear_addr.len() != 40 {
+                return Err(AddressError::InvalidLength {
+                    expected: 20,
+                    found: clear_addr.len() / 2,
+                });
+            }
 
-    let re = Regex::new(r"([0-9abcdef]+)([0-9abcdef]{8})").unwrap();
+            eip55::verify_checksum(clear_addr, options.chain_id, 2)?;
+        }
 
-    let caps = re.captures(&hex).unwrap();
+        return Ok(encode(&hex::decode(clear_addr).unwrap()));
+    }
+
+    let eth: EthAddress = address.parse()?;
 
-    if caps.len() != 3 as usize {
-        return Err("Invalid address");
+    if options.checksum {
+        eip55::verify_checksum(&address[2..], options.chain_id, 2)?;
     }
 
-    let mut match_addr = &caps[1];
+    Ok(VlxAddress::from(eth).to_string())
+}
 
-    if match_addr.len() > 40 {
-        let len = match_addr.len() - 40;
-        if match_addr.starts_with(&"0".repeat(len)) {
-            match_addr = match match_addr.get(len..match_addr.len()) {
-                Some(addr) => addr,
-                None => return Err("Invalid address"),
-            }
-        } else {
-            return Err("Invalid address");
+/// Convert VLX address to ETH address
+///
+/// ```rust
+/// use velas_address_rust::*;
+///
+/// let vlx_addresses = "V5dJeCa7bmkqmZF53TqjRbnB4fG6hxuu4f";
+/// assert_eq!(vlx_to_eth(vlx_addresses).unwrap(), "0x32be343b94f860124dc4fee278fdcbd38c102d88".to_string())
+/// ```
+///
+pub fn vlx_to_eth(address: &str) -> Result<String, AddressError> {
+    vlx_to_eth_with(address, Options::default())
+}
+
+/// Convert VLX address to ETH address with the given options
+///
+/// ```rust
+/// use velas_address_rust::*;
+///
+/// let vlx_addresses = "VA4oQ7mNj";
+/// assert!(vlx_to_eth_with(vlx_addresses, Options::new()).is_err());
+/// assert_eq!(vlx_to_eth_with(vlx_addresses, Options::new().lenient(true)).unwrap(), "0x1234");
+/// ```
+///
+pub fn vlx_to_eth_with(address: &str, options: Options) -> Result<String, AddressError> {
+    if options.lenient {
+        let clear_addr = hex::encode(decode(address)?);
+
+        if options.checksum {
+            return Ok(format!(
+                "0x{}",
+                eip55::to_checksum(&clear_addr, options.chain_id)
+            ));
         }
+
+        return Ok(format!("0x{}", clear_addr));
     }
 
-    let hash_big = hash_sha256(hash_sha256(match_addr.as_bytes()).as_bytes());
-    let checksum = match hash_big.get(0..8) {
-        Some(hash) => hash,
-        None => return Err("Invalid address"),
-    };
+    let eth = EthAddress::from(address.parse::<VlxAddress>()?);
 
-    if checksum != &caps[2] {
-        return Err("Invalid checksum");
+    if options.checksum {
+        return Ok(match options.chain_id {
+            Some(chain_id) => eth.to_checksum_with_chain_id(chain_id),
+            None => eth.to_checksum(),
+        });
     }
 
-    Ok(format!("0x{}", match_addr))
+    Ok(eth.to_string())
+}
+
+/// Convert VLX address to ETH address in EIP-55 mixed-case checksum format
+///
+/// ```rust
+/// use velas_address_rust::*;
+///
+/// let vlx_addresses = "V5dJeCa7bmkqmZF53TqjRbnB4fG6hxuu4f";
+/// assert_eq!(vlx_to_eth_checksum(vlx_addresses).unwrap(), "0x32Be343B94f860124dC4fEe278FDCBD38C102D88".to_string())
+/// ```
+///
+pub fn vlx_to_eth_checksum(address: &str) -> Result<String, AddressError> {
+    vlx_to_eth_with(address, Options::new().checksum(true))
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use core::convert::TryFrom;
+    use proptest::prelude::*;
 
     #[test]
     fn it_works() {
@@ -166,4 +459,1072 @@ mod tests {
             assert_eq!(vlx_addr.to_string(), addr.to_string());
         }
     }
+
+    #[test]
+    fn errors() {
+        assert_eq!(eth_to_vlx(""), Err(AddressError::MissingPrefix));
+        assert_eq!(
+            eth_to_vlx("32Be343B94f860124dC4fEe278FDCBD38C102D88"),
+            Err(AddressError::MissingPrefix)
+        );
+        assert_eq!(
+            eth_to_vlx("0x32Be343B94f860124dC4fEe278FDCBD38C102D8g"),
+            Err(AddressError::InvalidCharacter {
+                position: 41,
+                ch: 'g'
+            })
+        );
+
+        assert_eq!(vlx_to_eth(""), Err(AddressError::MissingPrefix));
+        assert_eq!(
+            vlx_to_eth("5dJeCa7bmkqmZF53TqjRbnB4fG6hxuu4f"),
+            Err(AddressError::MissingPrefix)
+        );
+        assert_eq!(
+            vlx_to_eth("V5dJeCa7bmkqmZF53TqjRbnB4fG6hxuu40"),
+            Err(AddressError::InvalidCharacter {
+                position: 33,
+                ch: '0'
+            })
+        );
+        assert!(matches!(
+            vlx_to_eth("V5dJeCa7bmkqmZF53TqjRbnB4fG6hxuu4g"),
+            Err(AddressError::ChecksumMismatch { .. })
+        ));
+    }
+
+    #[test]
+    fn checksum_steps() {
+        let vectors = [
+            (
+                "32be343b94f860124dc4fee278fdcbd38c102d88",
+                "86a1b0cebb427cb470a5fbde8a918d09fc82d646366de98177c9a3d871bda9b9",
+                "6db32c74bc9e5ceafb219765a0578d1ed5f9553ece0b67cf581020e5b92f0684",
+                "6db32c74",
+                "d9a537a9",
+            ),
+            (
+                "0000000000000000000000000000000000000000",
+                "9692e67b8378a6f6753f97782d458aa757e947eab2fbdf6b5c187b74561eb78f",
+                "6a225447eda9af7b0e556166d352e63411e89a9366a8134346434fbc797b0840",
+                "6a225447",
+                "f6eab7b9",
+            ),
+        ];
+
+        for (hex_text, first, second, checksum, raw) in vectors.iter() {
+            let first_hash = sha256::Hash::hash(hex_text.as_bytes());
+            assert_eq!(hex::encode(first_hash), *first);
+            let second_hash = sha256::Hash::hash(hex::encode(first_hash).as_bytes());
+            assert_eq!(hex::encode(second_hash), *second);
+
+            let mut address = [0u8; 20];
+            hex::decode_to_slice(hex_text, &mut address).unwrap();
+            assert_eq!(hex::encode(vlx_checksum(&address)), *checksum);
+            assert_eq!(hex::encode(hex_sha256d(&address)), *second);
+
+            // The common mistake of hashing the raw bytes
+            let raw_hash = sha256::Hash::hash(&sha256::Hash::hash(&address)[..]);
+            assert_eq!(hex::encode(&raw_hash[..4]), *raw);
+
+            let mut found = [0u8; 4];
+            hex::decode_to_slice(checksum, &mut found).unwrap();
+            assert_eq!(verify_vlx_checksum(&address, &found), Ok(()));
+            found[3] ^= 1;
+            assert!(matches!(
+                verify_vlx_checksum(&address, &found),
+                Err(AddressError::ChecksumMismatch { .. })
+            ));
+
+            let vlx = eth_to_vlx(&format!("0x{}", hex_text)).unwrap();
+            let decoded = decode_base58(&vlx[1..]).unwrap();
+            assert_eq!(hex::encode(&decoded[decoded.len() - 4..]), *checksum);
+        }
+    }
+
+    #[test]
+    fn codec() {
+        let codec = AddressCodec::VELAS;
+        let vlx_addresses = [
+            "V5dJeCa7bmkqmZF53TqjRbnB4fG6hxuu4f",
+            "V111111111111111111111111112jSS6vy",
+            "VNt1B3HD3MghPihCxhwMxNKRerBPPbiwvZ",
+            "V1111111111111111111111111113iMDfC",
+            "VQLbz7JHiBTspS962RLKV8GndWFwdcRndD",
+        ];
+        for addr in vlx_addresses.iter() {
+            let payload = codec.decode(addr).unwrap();
+            assert_eq!(payload[..], decode_vlx(addr).unwrap()[..]);
+            assert_eq!(codec.encode(&payload).unwrap(), *addr);
+        }
+
+        assert_eq!(
+            codec.decode("V15dJeCa7bmkqmZF53TqjRbnB4fG6hxuu4f"),
+            Err(AddressError::NonCanonical)
+        );
+        assert_eq!(
+            codec.decode("V5dJeCa7bmkqmZF53TqjRbnB4fG6hxuu4g"),
+            decode_vlx("V5dJeCa7bmkqmZF53TqjRbnB4fG6hxuu4g").map(|_| Vec::new())
+        );
+        assert_eq!(
+            codec.decode("V5dJeCa7bmkqmZF53TqjRbnB4fG6hxuu40"),
+            Err(AddressError::InvalidCharacter {
+                position: 33,
+                ch: '0'
+            })
+        );
+        assert_eq!(
+            codec.encode(&[0u8; 32]),
+            Err(AddressError::InvalidLength {
+                expected: 20,
+                found: 32
+            })
+        );
+
+        // Ripple alphabet, raw double SHA-256 and no padding
+        fn sha256d(payload: &[u8]) -> [u8; 32] {
+            sha256::Hash::hash(&sha256::Hash::hash(payload)[..]).into_inner()
+        }
+        const RIPPLE: AddressCodec = AddressCodec::VELAS
+            .prefix("")
+            .alphabet(b"rpshnaf39wBUDNEGHJKLM4PQRST7VWXYZ2bcdeCg65jkm8oFqi1tuvAxyz")
+            .checksum(sha256d)
+            .payload_len(21)
+            .padding(0);
+
+        // Account ID of the Ripple genesis account
+        let mut payload = [0u8; 21];
+        hex::decode_to_slice("00b5f762798a53d543a014caf8b297cff8f2f937e8", &mut payload).unwrap();
+        let address = RIPPLE.encode(&payload).unwrap();
+        assert_eq!(address, "rHb9CJAWyB4rj91VRWn96DkukG4bwdtyTh");
+        assert_eq!(RIPPLE.decode(&address).unwrap(), payload);
+        assert_eq!(
+            RIPPLE.decode("rrHb9CJAWyB4rj91VRWn96DkukG4bwdtyTh"),
+            Err(AddressError::NonCanonical)
+        );
+
+        let long = codec.checksum_len(32).prefix("VLX");
+        let address = long.encode(&payload[1..]).unwrap();
+        assert!(address.starts_with("VLX"));
+        assert_eq!(long.decode(&address).unwrap(), &payload[1..]);
+
+        let wide = codec.payload_len(100);
+        let address = wide.encode(&[0xab; 100]).unwrap();
+        assert_eq!(wide.decode(&address).unwrap(), &[0xab; 100][..]);
+
+        // Too many digits for 20 bytes of payload and 4 bytes of checksum
+        let overflow = format!("V{}", "z".repeat(40));
+        assert_eq!(
+            codec.decode(&overflow),
+            Err(AddressError::InvalidLength {
+                expected: 20,
+                found: 25
+            })
+        );
+        assert_eq!(
+            decode_vlx(&overflow),
+            Err(AddressError::NonCanonicalPadding)
+        );
+    }
+
+    #[test]
+    fn canonical() {
+        let address = "V5dJeCa7bmkqmZF53TqjRbnB4fG6hxuu4f";
+        let padded = "V15dJeCa7bmkqmZF53TqjRbnB4fG6hxuu4f";
+        assert_eq!(vlx_to_eth(padded), Err(AddressError::NonCanonical));
+        assert_eq!(decode_vlx(padded), Err(AddressError::NonCanonical));
+        assert_eq!(
+            padded.parse::<VlxAddress>(),
+            Err(AddressError::NonCanonical)
+        );
+        assert_eq!(canonicalize(padded).unwrap(), address);
+        assert_eq!(canonicalize(address).unwrap(), address);
+
+        // Zero bytes are encoded as '1's too, so both more and fewer '1's
+        // than the canonical 33 digits decode to the same account
+        let zero = eth_to_vlx("0x0000000000000000000000000000000000000000").unwrap();
+        let shorter = zero.replacen('1', "", 5);
+        let longer = zero.replacen('1', "111", 1);
+        for address in [&shorter, &longer].iter() {
+            assert_eq!(vlx_to_eth(address), Err(AddressError::NonCanonical));
+            assert_eq!(canonicalize(address).unwrap(), zero);
+        }
+        assert_eq!(
+            vlx_to_eth_with(&longer, Options::new().lenient(true)).unwrap(),
+            "0x0000000000000000000000000000000000000000"
+        );
+
+        assert_eq!(
+            canonicalize("V5dJeCa7bmkqmZF53TqjRbnB4fG6hxuu4g"),
+            Err(AddressError::ChecksumMismatch {
+                expected: [0x6d, 0xb3, 0x2c, 0x74],
+                found: [0x6d, 0xb3, 0x2c, 0x75]
+            })
+        );
+        assert_eq!(
+            canonicalize("VA4oQ7mNj"),
+            Err(AddressError::InvalidLength {
+                expected: 20,
+                found: 2
+            })
+        );
+    }
+
+    #[test]
+    fn suggest() {
+        let address = "V5dJeCa7bmkqmZF53TqjRbnB4fG6hxuu4f";
+        let typos = [
+            "V5dJeCa7bmkqmZF53TqjRbnB4fG6hxuu4F",
+            "V5dJeCa7bmkqmZF53TqjRbnB4fG6hxuu40",
+            "V5dJeCa7bmkqmZF53TqjRbnB4fG6hxu4uf",
+            "V5dJeCa7bmkqmZF53TqjRbnB4fG6hxuu5f",
+            "V5dJeCa7bmkqmZF53TqjRbnB4fG6hxu4f",
+            "V5dJeCa7bmkqmZF53TqjRbnB4fG6hxuuu4f",
+        ];
+        for typo in typos.iter() {
+            assert!(vlx_to_eth(typo).is_err());
+            assert_eq!(suggest_corrections(typo)[0].to_string(), address);
+        }
+
+        assert_eq!(suggest_corrections(address).len(), 1);
+        assert!(suggest_corrections("").is_empty());
+        assert!(suggest_corrections("5dJeCa7bmkqmZF53TqjRbnB4fG6hxuu4F").is_empty());
+        assert!(suggest_corrections("V5dJeCa7bmkqmZF53TqjRbn").is_empty());
+        assert!(suggest_corrections(&format!("V{}", "2".repeat(800))).is_empty());
+    }
+
+    #[test]
+    fn short_payload() {
+        assert_eq!(
+            vlx_to_eth("V"),
+            Err(AddressError::InvalidLength {
+                expected: 20,
+                found: 0
+            })
+        );
+        assert_eq!(
+            vlx_to_eth("V1"),
+            Err(AddressError::InvalidLength {
+                expected: 20,
+                found: 0
+            })
+        );
+        assert_eq!(
+            eth_to_vlx("0xzz"),
+            Err(AddressError::InvalidCharacter {
+                position: 2,
+                ch: 'z'
+            })
+        );
+        assert_eq!(eth_to_vlx("0x123"), Err(AddressError::OddLength));
+        assert_eq!(
+            vlx_to_eth("V5dJeCa7bmkqmZF53TqjRbnB4fG6hxuu4ф"),
+            Err(AddressError::InvalidCharacter {
+                position: 33,
+                ch: 'ф'
+            })
+        );
+    }
+
+    #[test]
+    fn lenient() {
+        let lenient = Options::new().lenient(true);
+        let tx_hash = "0x88df016429689c079f3b2f6ad39fa052532c56795b733da78a91ebe6a713944b";
+
+        assert_eq!(
+            eth_to_vlx(tx_hash),
+            Err(AddressError::InvalidLength {
+                expected: 20,
+                found: 32
+            })
+        );
+
+        let vlx_addr = eth_to_vlx_with(tx_hash, lenient).unwrap();
+        assert_eq!(
+            vlx_to_eth(&vlx_addr),
+            Err(AddressError::NonCanonicalPadding)
+        );
+        assert_eq!(
+            vlx_to_eth_with(&vlx_addr, lenient),
+            Err(AddressError::NonCanonicalPadding)
+        );
+        assert_eq!(
+            vlx_to_eth("VA4oQ7mNj"),
+            Err(AddressError::InvalidLength {
+                expected: 20,
+                found: 2
+            })
+        );
+        assert_eq!(vlx_to_eth_with("VA4oQ7mNj", lenient).unwrap(), "0x1234");
+        assert_eq!(
+            eth_to_vlx_with("0x1234", lenient).unwrap(),
+            "V1111111111111111111111111A4oQ7mNj"
+        );
+
+        assert_eq!(
+            eth_to_vlx_with("0x123", lenient),
+            Err(AddressError::OddLength)
+        );
+        assert_eq!(
+            AddressError::OddLength.to_string(),
+            "Invalid address: odd number of hex digits"
+        );
+
+        let addr = "0x000000000000000000000000000000000000000f";
+        let vlx_addr = eth_to_vlx_with(addr, lenient).unwrap();
+        assert_eq!(vlx_to_eth_with(&vlx_addr, lenient).unwrap(), addr);
+
+        let checksum = lenient.checksum(true);
+        assert_eq!(
+            eth_to_vlx_with(&format!("0x{}", "aA".repeat(33)), checksum),
+            Err(AddressError::InvalidLength {
+                expected: 20,
+                found: 33
+            })
+        );
+        assert_eq!(
+            eth_to_vlx_with("0x1234", checksum),
+            Err(AddressError::InvalidLength {
+                expected: 20,
+                found: 2
+            })
+        );
+        assert!(eth_to_vlx_with("0x32Be343B94f860124dC4fEe278FDCBD38C102D88", checksum).is_ok());
+    }
+
+    #[test]
+    fn eip55() {
+        // Test vectors from EIP-55
+        let eth_addresses = [
+            "0x5aAeb6053F3E94C9b9A09f33669435E7Ef1BeAed",
+            "0xfB6916095ca1df60bB79Ce92cE3Ea74c37c5d359",
+            "0xdbF03B407c01E7cD3CBea99509d93f8DDDC8C6FB",
+            "0xD1220A0cf47c7B9Be7A2E6BA89F429762e7b9aDb",
+        ];
+
+        let checksum = Options::new().checksum(true);
+
+        for addr in eth_addresses.iter() {
+            let eth: EthAddress = addr.parse().unwrap();
+            assert_eq!(eth.to_checksum(), *addr);
+
+            let vlx_addr = eth_to_vlx_with(addr, checksum).unwrap();
+            assert_eq!(vlx_to_eth_checksum(&vlx_addr).unwrap(), *addr);
+            assert_eq!(
+                vlx_to_eth_with(&vlx_addr, checksum.lenient(true)).unwrap(),
+                *addr
+            );
+        }
+
+        assert!(eth_to_vlx_with("0x5aaeb6053f3e94c9b9a09f33669435e7ef1beaed", checksum).is_ok());
+        assert!(eth_to_vlx_with("0x5AAEB6053F3E94C9B9A09F33669435E7EF1BEAED", checksum).is_ok());
+        assert!(eth_to_vlx("0x5aAeb6053F3E94C9b9A09f33669435E7Ef1BeAeD").is_ok());
+        assert_eq!(
+            eth_to_vlx_with("0x5aAeb6053F3E94C9b9A09f33669435E7Ef1BeAeD", checksum),
+            Err(AddressError::InvalidChecksumCase {
+                position: 41,
+                ch: 'D'
+            })
+        );
+    }
+
+    #[test]
+    fn eip1191() {
+        // Test vectors from EIP-1191
+        let eth_addresses = [
+            (
+                30,
+                [
+                    "0x5aaEB6053f3e94c9b9a09f33669435E7ef1bEAeD",
+                    "0xFb6916095cA1Df60bb79ce92cE3EA74c37c5d359",
+                    "0xDBF03B407c01E7CD3cBea99509D93F8Dddc8C6FB",
+                    "0xD1220A0Cf47c7B9BE7a2e6ba89F429762E7B9adB",
+                ],
+            ),
+            (
+                31,
+                [
+                    "0x5aAeb6053F3e94c9b9A09F33669435E7EF1BEaEd",
+                    "0xFb6916095CA1dF60bb79CE92ce3Ea74C37c5D359",
+                    "0xdbF03B407C01E7cd3cbEa99509D93f8dDDc8C6fB",
+                    "0xd1220a0CF47c7B9Be7A2E6Ba89f429762E7b9adB",
+                ],
+            ),
+        ];
+
+        for (chain_id, addrs) in eth_addresses.iter() {
+            let checksum = Options::new().checksum(true).chain_id(Some(*chain_id));
+
+            for addr in addrs.iter() {
+                let eth: EthAddress = addr.parse().unwrap();
+                assert_eq!(eth.to_checksum_with_chain_id(*chain_id), *addr);
+
+                let vlx_addr = eth_to_vlx_with(addr, checksum).unwrap();
+                assert_eq!(vlx_to_eth_with(&vlx_addr, checksum).unwrap(), *addr);
+                assert!(eth_to_vlx_with(addr, Options::new().checksum(true)).is_err());
+            }
+        }
+
+        let eth: EthAddress = "0x32be343b94f860124dc4fee278fdcbd38c102d88"
+            .parse()
+            .unwrap();
+        for chain_id in [VELAS_MAINNET_CHAIN_ID, VELAS_TESTNET_CHAIN_ID].iter() {
+            let checksum = Options::new().checksum(true).chain_id(Some(*chain_id));
+            let addr = eth.to_checksum_with_chain_id(*chain_id);
+            assert!(eth_to_vlx_with(&addr, checksum).is_ok());
+        }
+    }
+
+    #[test]
+    fn native_addresses() {
+        let native_addresses = [
+            "Vote111111111111111111111111111111111111111",
+            "11111111111111111111111111111111",
+            "4Nd1mBQtrMJVYVfKf2PJy9NZUZdTAsp7D4xWLs4gDB4T",
+        ];
+
+        for addr in native_addresses.iter() {
+            let native: NativeAddress = addr.parse().unwrap();
+            assert_eq!(native.to_string(), *addr);
+            assert_eq!(NativeAddress::try_from(&native.as_bytes()[..]), Ok(native));
+            assert!(vlx_to_eth(addr).is_err());
+        }
+
+        assert_eq!(
+            "V5dJeCa7bmkqmZF53TqjRbnB4fG6hxuu4f".parse::<NativeAddress>(),
+            Err(AddressError::InvalidLength {
+                expected: 32,
+                found: 25
+            })
+        );
+        assert_eq!(
+            "".parse::<NativeAddress>(),
+            Err(AddressError::InvalidLength {
+                expected: 32,
+                found: 0
+            })
+        );
+
+        // Longer strings are rejected without decoding, with a lower bound
+        // of the decoded length
+        for digits in ["z", "2", "1z", "11111112", "1"].iter() {
+            for len in 45..100 {
+                let addr = digits.repeat(len)[..len].to_string();
+                let decoded = decode_base58(&addr).unwrap().len();
+                match addr.parse::<NativeAddress>() {
+                    Err(AddressError::InvalidLength {
+                        expected: 32,
+                        found,
+                    }) => {
+                        assert!(32 < found && found <= decoded)
+                    }
+                    result => panic!("{:?}", result),
+                }
+            }
+        }
+        assert!(matches!(
+            detect(&"z".repeat(40_000)),
+            Err(AddressError::InvalidLength { expected: 32, .. })
+        ));
+        assert_eq!(
+            format!("{}0", "z".repeat(50)).parse::<NativeAddress>(),
+            Err(AddressError::InvalidCharacter {
+                position: 50,
+                ch: '0'
+            })
+        );
+        assert_eq!(
+            "4Nd1mBQtrMJVYVfKf2PJy9NZUZdTAsp7D4xWLs4gDB40".parse::<NativeAddress>(),
+            Err(AddressError::InvalidCharacter {
+                position: 43,
+                ch: '0'
+            })
+        );
+    }
+
+    #[test]
+    fn detect_kind() {
+        let eth: EthAddress = "0x32Be343B94f860124dC4fEe278FDCBD38C102D88"
+            .parse()
+            .unwrap();
+
+        assert_eq!(
+            detect("0x32Be343B94f860124dC4fEe278FDCBD38C102D88"),
+            Ok(AddressKind::Eth(eth))
+        );
+        assert_eq!(
+            detect("V5dJeCa7bmkqmZF53TqjRbnB4fG6hxuu4f"),
+            Ok(AddressKind::Vlx(VlxAddress::from(eth)))
+        );
+        assert_eq!(
+            detect("Vote111111111111111111111111111111111111111")
+                .unwrap()
+                .as_bytes()
+                .len(),
+            32
+        );
+        assert_eq!(
+            detect("4Nd1mBQtrMJVYVfKf2PJy9NZUZdTAsp7D4xWLs4gDB4T")
+                .unwrap()
+                .to_string(),
+            "4Nd1mBQtrMJVYVfKf2PJy9NZUZdTAsp7D4xWLs4gDB4T"
+        );
+
+        assert_eq!(
+            detect("0x32be"),
+            Err(AddressError::InvalidLength {
+                expected: 20,
+                found: 2
+            })
+        );
+        assert!(matches!(
+            detect("V5dJeCa7bmkqmZF53TqjRbnB4fG6hxuu4g"),
+            Err(AddressError::ChecksumMismatch { .. })
+        ));
+        assert_eq!(
+            detect("4Nd1mBQtrMJVYVfKf2PJy9"),
+            Err(AddressError::InvalidLength {
+                expected: 32,
+                found: 16
+            })
+        );
+    }
+
+    #[test]
+    fn contract() {
+        let sender = "0x6ac7ea33f8831ea9dcc53393aaa88b25a785dbf0";
+        let created = [
+            (0, "0xcd234a471b72ba2f1ccf0a70fcaba648a5eecd8d"),
+            (1, "0x343c43a37d37dff08ae8c4a11544c718abb4fcf8"),
+            (2, "0xf778b86fa74e846c4f0a1fbd1335fe81c00a0c91"),
+            (3, "0xfffd933a0bc612844eaf0c6fe3e5b8e9b6c1d19c"),
+        ];
+        for (nonce, address) in created.iter() {
+            let (eth, vlx) = create_address(sender, *nonce).unwrap();
+            assert_eq!(eth.to_string(), *address);
+            assert_eq!(vlx.to_string(), eth_to_vlx(address).unwrap());
+        }
+
+        // Nonces from 0x80 on are prefixed with their length
+        let rlp = [
+            ("d794", 0x80, "8180"),
+            ("d894", 0x1234, "821234"),
+            ("de94", u64::MAX, "88ffffffffffffffff"),
+        ];
+        for (list, nonce, item) in rlp.iter() {
+            let encoded = hex::decode(format!("{}{}{}", list, &sender[2..], item)).unwrap();
+            let (eth, _) = create_address(sender, *nonce).unwrap();
+            assert_eq!(eth.as_bytes()[..], keccak256(&encoded)[12..]);
+        }
+
+        let vlx_sender = eth_to_vlx(sender).unwrap();
+        assert_eq!(
+            create_address(&vlx_sender, 0x80).unwrap(),
+            create_address(sender, 0x80).unwrap()
+        );
+
+        // Vectors from EIP-1014
+        let create2 = [
+            (
+                "0x0000000000000000000000000000000000000000",
+                "0000000000000000000000000000000000000000000000000000000000000000",
+                "00",
+                "0x4D1A2e2bB4F88F0250f26Ffff098B0b30B26BF38",
+            ),
+            (
+                "0xdeadbeef00000000000000000000000000000000",
+                "0000000000000000000000000000000000000000000000000000000000000000",
+                "00",
+                "0xB928f69Bb1D91Cd65274e3c79d8986362984fDA3",
+            ),
+            (
+                "0xdeadbeef00000000000000000000000000000000",
+                "000000000000000000000000feed000000000000000000000000000000000000",
+                "00",
+                "0xD04116cDd17beBE565EB2422F2497E06cC1C9833",
+            ),
+            (
+                "0x0000000000000000000000000000000000000000",
+                "0000000000000000000000000000000000000000000000000000000000000000",
+                "deadbeef",
+                "0x70f2b2914A2a4b783FaEFb75f459A580616Fcb5e",
+            ),
+            (
+                "0x00000000000000000000000000000000deadbeef",
+                "00000000000000000000000000000000000000000000000000000000cafebabe",
+                "deadbeef",
+                "0x60f3f640a8508fC6a86d45DF051962668E1e8AC7",
+            ),
+            (
+                "0x00000000000000000000000000000000deadbeef",
+                "00000000000000000000000000000000000000000000000000000000cafebabe",
+                "deadbeefdeadbeefdeadbeefdeadbeefdeadbeefdeadbeefdeadbeefdeadbeefdeadbeefdeadbeefdeadbeef",
+                "0x1d8bfDC5D46DC4f61D6b6115972536eBE6A8854C",
+            ),
+            (
+                "0x0000000000000000000000000000000000000000",
+                "0000000000000000000000000000000000000000000000000000000000000000",
+                "",
+                "0xE33C0C7F7df4809055C3ebA6c09CFe4BaF1BD9e0",
+            ),
+        ];
+        for (deployer, salt, init_code, address) in create2.iter() {
+            let mut salt_bytes = [0u8; 32];
+            hex::decode_to_slice(salt, &mut salt_bytes).unwrap();
+            let init_code_hash = keccak256(&hex::decode(init_code).unwrap());
+
+            let (eth, vlx) = create2_address(deployer, &salt_bytes, &init_code_hash).unwrap();
+            assert_eq!(eth.to_checksum(), *address);
+            assert_eq!(vlx.to_string(), eth_to_vlx(address).unwrap());
+
+            let vlx_deployer = eth_to_vlx(deployer).unwrap();
+            assert_eq!(
+                create2_address(&vlx_deployer, &salt_bytes, &init_code_hash).unwrap(),
+                (eth, vlx)
+            );
+        }
+
+        assert_eq!(
+            create_address("6ac7ea33f8831ea9dcc53393aaa88b25a785dbf0", 0),
+            Err(AddressError::MissingPrefix)
+        );
+        assert_eq!(
+            create2_address("0x6ac7", &[0u8; 32], &[0u8; 32]),
+            Err(AddressError::InvalidLength {
+                expected: 20,
+                found: 2
+            })
+        );
+    }
+
+    #[cfg(feature = "keys")]
+    #[test]
+    fn keys() {
+        let private_key =
+            hex::decode("4c0883a69102937d6231471b5dbb6204fe5129617082792ae468d01a3f362318")
+                .unwrap();
+        let mut key = [0u8; 32];
+        key.copy_from_slice(&private_key);
+
+        let (eth, vlx) = from_private_key(&key).unwrap();
+        assert_eq!(
+            eth.to_checksum(),
+            "0x2c7536E3605D9C16a7a3D7b1898e529396a65c23"
+        );
+        assert_eq!(VlxAddress::from(eth), vlx);
+
+        let uncompressed = hex::decode(
+            "0479be667ef9dcbbac55a06295ce870b07029bfcdb2dce28d959f2815b16f81798\
+             483ada7726a3c4655da4fbfc0e1108a8fd17b448a68554199c47d08ffb10d4b8",
+        )
+        .unwrap();
+        let compressed =
+            hex::decode("0279be667ef9dcbbac55a06295ce870b07029bfcdb2dce28d959f2815b16f81798")
+                .unwrap();
+        assert_eq!(
+            from_public_key(&uncompressed).unwrap(),
+            from_public_key(&compressed).unwrap()
+        );
+        assert_eq!(
+            from_public_key(&compressed).unwrap().0.to_checksum(),
+            "0x7E5F4552091A69125d5DfCb7b8C2659029395Bdf"
+        );
+
+        assert_eq!(from_private_key(&[0u8; 32]), Err(AddressError::InvalidKey));
+        assert_eq!(
+            from_private_key(&[0xffu8; 32]),
+            Err(AddressError::InvalidKey)
+        );
+        assert_eq!(from_public_key(&[5u8; 33]), Err(AddressError::InvalidKey));
+        assert_eq!(
+            from_public_key(&compressed[1..]),
+            Err(AddressError::InvalidLength {
+                expected: 65,
+                found: 32
+            })
+        );
+    }
+
+    #[cfg(feature = "hd")]
+    #[test]
+    fn hd() {
+        let phrase = "test test test test test test test test test test test junk";
+        let wallet = HdWallet::from_mnemonic(phrase, "").unwrap();
+
+        // Well-known Ethereum addresses of this mnemonic
+        let (eth, vlx) = wallet.derive("m/44'/60'/0'/0/0").unwrap();
+        assert_eq!(
+            eth.to_checksum(),
+            "0xf39Fd6e51aad88F6F4ce6aB8827279cffFb92266"
+        );
+        assert_eq!(VlxAddress::from(eth), vlx);
+        let (eth, _) = wallet.derive("m/44'/60'/0'/0/1").unwrap();
+        assert_eq!(
+            eth.to_checksum(),
+            "0x70997970C51812dc3A010C7d01b50e0d17dc79C8"
+        );
+
+        // Velas coin type, cross-checked against a separate from-scratch BIP-39,
+        // BIP-32 and Keccak-256 implementation that reproduces the Ethereum
+        // vectors above. Not checked against an export of the Velas wallet yet.
+        let (eth, vlx) = wallet
+            .derive(&format!("{}/0", VELAS_DERIVATION_PATH))
+            .unwrap();
+        assert_eq!(
+            eth.to_checksum(),
+            "0xE8140ADA05D6eFA91E106d2F27f1B20008A8Fdcb"
+        );
+        assert_eq!(vlx.to_string(), "VNA7n5JsJDVwmXgL7D9Q8sB4HZUw6SP9BZ");
+
+        // The xpub of the account derives the same addresses
+        let xpub = wallet.to_xpub("m/44'/5655640'/0'").unwrap();
+        let public = HdWallet::from_extended_key(&xpub).unwrap();
+        for i in 0..5 {
+            assert_eq!(
+                wallet
+                    .derive(&format!("{}/{}", VELAS_DERIVATION_PATH, i))
+                    .unwrap(),
+                public.derive(&format!("m/0/{}", i)).unwrap()
+            );
+        }
+
+        assert!(public.derive("m/0'/0").is_err());
+        assert!(wallet.derive("m/44'/x").is_err());
+        assert!(HdWallet::from_mnemonic("test test test", "").is_err());
+        assert!(HdWallet::from_extended_key("xpub").is_err());
+    }
+
+    #[cfg(feature = "batch")]
+    #[test]
+    fn batch() {
+        let input = "{\"addr\":\"V5dJeCa7bmkqmZF53TqjRbnB4fG6hxuu4f\"}\n\n{\"addr\":\"V1\"}\n{}\n\
+                     {\"addr\":1}\n[]\n{\"addr\n{\"addr\":\"V5dJeCa7bmkqmZF53TqjRbnB4fG6hxuu4f\"}\n";
+        let mut output = Vec::new();
+
+        let report = BatchConverter::new("addr", Direction::ToEth)
+            .options(Options::new().checksum(true))
+            .status_column("ok")
+            .convert_json_lines(input.as_bytes(), &mut output)
+            .unwrap();
+
+        assert_eq!(report.rows, 7);
+        assert_eq!(
+            report.errors[..3],
+            [
+                RowError {
+                    row: 2,
+                    error: RowErrorKind::Address(AddressError::InvalidLength {
+                        expected: 20,
+                        found: 0
+                    })
+                },
+                RowError {
+                    row: 3,
+                    error: RowErrorKind::MissingField
+                },
+                RowError {
+                    row: 4,
+                    error: RowErrorKind::MissingField
+                },
+            ]
+        );
+        assert_eq!(
+            report.errors[3].error,
+            RowErrorKind::Malformed("expected a JSON object".to_string())
+        );
+        assert_eq!(report.errors[4].row, 6);
+        assert!(matches!(report.errors[4].error, RowErrorKind::Malformed(_)));
+        assert_eq!(report.errors.len(), 5);
+
+        let lines: Vec<serde_json::Value> = String::from_utf8(output)
+            .unwrap()
+            .lines()
+            .map(|line| serde_json::from_str(line).unwrap())
+            .collect();
+        assert_eq!(
+            lines[0]["addr_eth"],
+            "0x32Be343B94f860124dC4fEe278FDCBD38C102D88"
+        );
+        assert_eq!(lines[0]["ok"], "valid");
+        assert_eq!(lines[1]["addr_eth"], serde_json::Value::Null);
+        assert_eq!(lines[2]["ok"], "Missing address field");
+        assert_eq!(lines[4]["ok"], "Malformed row: expected a JSON object");
+        assert_eq!(lines[6]["ok"], "valid");
+
+        let input = "id,address\n1,0x32Be343B94f860124dC4fEe278FDCBD38C102D88\n2\n\
+                     3,0x32Be343B94f860124dC4fEe278FDCBD38C102D88,x\n4,0x32Be343B94f860124dC4fEe278FDCBD38C102D88\n";
+        let mut output = Vec::new();
+        let report = BatchConverter::new("address", Direction::ToVlx)
+            .convert_csv(input.as_bytes(), &mut output)
+            .unwrap();
+        assert_eq!(report.rows, 4);
+        assert_eq!(
+            report.errors,
+            vec![
+                RowError {
+                    row: 2,
+                    error: RowErrorKind::Malformed("expected 2 fields, found 1".to_string())
+                },
+                RowError {
+                    row: 3,
+                    error: RowErrorKind::Malformed("expected 2 fields, found 3".to_string())
+                },
+            ]
+        );
+        assert_eq!(
+            String::from_utf8(output).unwrap().lines().nth(2).unwrap(),
+            "2,,,\"Malformed row: expected 2 fields, found 1\""
+        );
+
+        let mut output = Vec::new();
+        let report = BatchConverter::new("address", Direction::ToVlx)
+            .convert_csv(
+                &b"address\n\xff\n0x32Be343B94f860124dC4fEe278FDCBD38C102D88\n"[..],
+                &mut output,
+            )
+            .unwrap();
+        assert_eq!(report.rows, 2);
+        assert_eq!(report.errors.len(), 1);
+        assert!(matches!(report.errors[0].error, RowErrorKind::Malformed(_)));
+        assert!(matches!(
+            BatchConverter::new("addr", Direction::ToEth)
+                .convert_csv(&b"address\n"[..], Vec::new()),
+            Err(BatchError::MissingColumn(_))
+        ));
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn serde() {
+        use ::serde::{Deserialize, Serialize};
+
+        #[derive(Serialize, Deserialize, Debug, PartialEq)]
+        struct Config {
+            eth: EthAddress,
+            native: NativeAddress,
+            #[serde(with = "crate::serde::as_vlx")]
+            as_vlx: EthAddress,
+            #[serde(with = "crate::serde::as_eth")]
+            as_eth: VlxAddress,
+            #[serde(with = "crate::serde::as_eth_checksum")]
+            as_eth_checksum: EthAddress,
+        }
+
+        let eth: EthAddress = "0x32Be343B94f860124dC4fEe278FDCBD38C102D88"
+            .parse()
+            .unwrap();
+        let config = Config {
+            eth,
+            native: "Vote111111111111111111111111111111111111111"
+                .parse()
+                .unwrap(),
+            as_vlx: eth,
+            as_eth: eth.into(),
+            as_eth_checksum: eth,
+        };
+
+        let json = serde_json::to_value(&config).unwrap();
+        assert_eq!(
+            json,
+            serde_json::json!({
+                "eth": "0x32be343b94f860124dc4fee278fdcbd38c102d88",
+                "native": "Vote111111111111111111111111111111111111111",
+                "as_vlx": "V5dJeCa7bmkqmZF53TqjRbnB4fG6hxuu4f",
+                "as_eth": "0x32be343b94f860124dc4fee278fdcbd38c102d88",
+                "as_eth_checksum": "0x32Be343B94f860124dC4fEe278FDCBD38C102D88",
+            })
+        );
+        assert_eq!(serde_json::from_value::<Config>(json).unwrap(), config);
+
+        // The helpers accept both formats on input
+        let json = serde_json::json!({
+            "eth": "0x32be343b94f860124dc4fee278fdcbd38c102d88",
+            "native": "Vote111111111111111111111111111111111111111",
+            "as_vlx": "0x32be343b94f860124dc4fee278fdcbd38c102d88",
+            "as_eth": "V5dJeCa7bmkqmZF53TqjRbnB4fG6hxuu4f",
+            "as_eth_checksum": "V5dJeCa7bmkqmZF53TqjRbnB4fG6hxuu4f",
+        });
+        assert_eq!(serde_json::from_value::<Config>(json).unwrap(), config);
+
+        assert!(
+            serde_json::from_str::<EthAddress>("\"V5dJeCa7bmkqmZF53TqjRbnB4fG6hxuu4f\"").is_err()
+        );
+        assert_eq!(
+            serde_json::from_str::<VlxAddress>("\"V5dJeCa7bmkqmZF53TqjRbnB4fG6hxuu4g\"")
+                .unwrap_err()
+                .to_string(),
+            "Invalid checksum: expected 6db32c74, found 6db32c75 at line 1 column 36"
+        );
+        assert!(serde_json::from_str::<VlxAddress>("42").is_err());
+    }
+
+    #[cfg(feature = "vanity")]
+    #[test]
+    fn vanity() {
+        let search = VanitySearch::new().prefix("VL").suffix("X").threads(2);
+        let mut updates = 0;
+        let found = search
+            .search_with_progress(std::time::Duration::from_millis(1), |progress| {
+                assert!(progress.probability().unwrap() < 1.0);
+                updates += 1;
+            })
+            .unwrap();
+
+        let vlx = found.vlx.to_string();
+        assert!(vlx.starts_with("VL") && vlx.ends_with('X'));
+        assert_eq!(VlxAddress::from(found.eth), found.vlx);
+        assert_eq!(
+            from_private_key(found.private_key.as_bytes()).unwrap(),
+            (found.eth, found.vlx)
+        );
+        assert!(found.attempts > 0);
+        assert!(updates > 0 || found.attempts < 10_000);
+        assert_eq!(format!("{:?}", found.private_key), "PrivateKey(..)");
+
+        let found = VanitySearch::new()
+            .prefix("vl")
+            .case_sensitive(false)
+            .search()
+            .unwrap();
+        assert!(found.vlx.to_string().starts_with("VL"));
+
+        // The first digit after the `V` is at most `Q`
+        assert_eq!(
+            VanitySearch::new()
+                .prefix("VLX")
+                .difficulty()
+                .unwrap()
+                .round(),
+            (2f64.powi(192) / 58f64.powi(31)).round()
+        );
+        assert_eq!(
+            VanitySearch::new()
+                .suffix("ab1")
+                .case_sensitive(false)
+                .difficulty()
+                .unwrap(),
+            58f64.powi(3) / 4.0
+        );
+        assert_eq!(VanitySearch::new().difficulty(), Some(1.0));
+
+        assert_eq!(
+            VanitySearch::new().prefix("LX").search().unwrap_err(),
+            VanityError::MissingPrefix
+        );
+        assert_eq!(
+            VanitySearch::new().prefix("V0").search().unwrap_err(),
+            VanityError::InvalidCharacter {
+                position: 1,
+                ch: '0'
+            }
+        );
+        assert_eq!(
+            VanitySearch::new().suffix("Il").search().unwrap_err(),
+            VanityError::InvalidCharacter {
+                position: 32,
+                ch: 'I'
+            }
+        );
+        assert_eq!(
+            VanitySearch::new().prefix("VR").search().unwrap_err(),
+            VanityError::Unreachable
+        );
+        assert!(VanitySearch::new().prefix("VQL").difficulty().is_some());
+        assert_eq!(
+            VanitySearch::new().prefix("VQM").search().unwrap_err(),
+            VanityError::Unreachable
+        );
+
+        #[cfg(feature = "vanity-regex")]
+        {
+            let regex = regex::Regex::new("^V[1-9]").unwrap();
+            let search = VanitySearch::new().regex(regex);
+            assert_eq!(search.difficulty(), None);
+            assert!(search.search().unwrap().vlx.to_string()[1..2]
+                .chars()
+                .all(|ch| ch.is_ascii_digit()));
+        }
+    }
+
+    /// Every combination of `Options`
+    fn all_options() -> impl Iterator<Item = Options> {
+        [false, true].iter().flat_map(|lenient| {
+            [false, true].iter().flat_map(move |checksum| {
+                [None, Some(VELAS_MAINNET_CHAIN_ID)]
+                    .iter()
+                    .map(move |chain_id| {
+                        Options::new()
+                            .lenient(*lenient)
+                            .checksum(*checksum)
+                            .chain_id(*chain_id)
+                    })
+            })
+        })
+    }
+
+    proptest! {
+        #[test]
+        fn never_panics(address in "\\PC*") {
+            for options in all_options() {
+                let _ = eth_to_vlx_with(&address, options);
+                let _ = vlx_to_eth_with(&address, options);
+            }
+            let _ = address.parse::<NativeAddress>();
+            let _ = detect(&address);
+        }
+
+        #[test]
+        fn roundtrip(address in any::<[u8; 20]>()) {
+            let mut buf = [0u8; 34];
+            let vlx_addr = encode_vlx_into(&address, &mut buf);
+
+            prop_assert_eq!(vlx_addr, eth_to_vlx(&format!("0x{}", hex::encode(address))).unwrap());
+            prop_assert_eq!(decode_vlx(vlx_addr), Ok(address));
+        }
+
+        #[test]
+        fn never_panics_with_prefix(eth in "0x[0-9a-fA-Fx]{0,64}", vlx in "V[1-9A-Za-z]{0,64}") {
+            for options in all_options() {
+                let _ = eth_to_vlx_with(&eth, options);
+                let _ = vlx_to_eth_with(&vlx, options);
+            }
+        }
+    }
+
+    #[test]
+    fn typed_addresses() {
+        let eth: EthAddress = "0x32Be343B94f860124dC4fEe278FDCBD38C102D88"
+            .parse()
+            .unwrap();
+        let vlx: VlxAddress = "V5dJeCa7bmkqmZF53TqjRbnB4fG6hxuu4f".parse().unwrap();
+
+        assert_eq!(VlxAddress::from(eth), vlx);
+        assert_eq!(EthAddress::from(vlx), eth);
+        assert_eq!(eth.as_bytes(), vlx.as_bytes());
+        assert_eq!(EthAddress::try_from(&eth.as_bytes()[..]), Ok(eth));
+        assert_eq!(VlxAddress::try_from(&vlx.as_bytes()[..]), Ok(vlx));
+        assert_eq!(
+            format!("{:?}", eth),
+            "EthAddress(0x32be343b94f860124dc4fee278fdcbd38c102d88)"
+        );
+        assert_eq!(
+            format!("{:?}", vlx),
+            "VlxAddress(V5dJeCa7bmkqmZF53TqjRbnB4fG6hxuu4f)"
+        );
+
+        assert_eq!(
+            "0x32be".parse::<EthAddress>(),
+            Err(AddressError::InvalidLength {
+                expected: 20,
+                found: 2
+            })
+        );
+        assert_eq!(
+            EthAddress::try_from(&[0u8; 32][..]),
+            Err(AddressError::InvalidLength {
+                expected: 20,
+                found: 32
+            })
+        );
+    }
 }
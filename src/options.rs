@@ -0,0 +1,62 @@
+/// Options for [`eth_to_vlx_with`](crate::eth_to_vlx_with) and
+/// [`vlx_to_eth_with`](crate::vlx_to_eth_with)
+///
+/// ```rust
+/// use velas_address_rust::*;
+///
+/// let options = Options::new().lenient(true);
+/// assert_eq!(eth_to_vlx_with("0x1234", options).unwrap(), "V1111111111111111111111111A4oQ7mNj");
+/// ```
+///
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct Options {
+    pub(crate) lenient: bool,
+    pub(crate) checksum: bool,
+    pub(crate) chain_id: Option<u64>,
+}
+
+impl Options {
+    /// Strict options, same as used by `eth_to_vlx` and `vlx_to_eth`
+    pub fn new() -> Self {
+        Options::default()
+    }
+
+    /// Accept addresses of any byte length instead of exactly 20 bytes
+    ///
+    /// This matches the behavior of older releases and should only be used
+    /// for legacy data.
+    pub fn lenient(mut self, lenient: bool) -> Self {
+        self.lenient = lenient;
+        self
+    }
+
+    /// Use EIP-55 mixed-case checksums for ETH addresses
+    ///
+    /// `vlx_to_eth_with` returns checksummed addresses and `eth_to_vlx_with` rejects
+    /// mixed-case input with a wrong checksum. All-lowercase and all-uppercase input
+    /// is still accepted. Together with [`lenient`](Options::lenient), `eth_to_vlx_with`
+    /// rejects input that is not 20 bytes long.
+    pub fn checksum(mut self, checksum: bool) -> Self {
+        self.checksum = checksum;
+        self
+    }
+
+    /// Chain ID for EIP-1191 checksums, `None` for plain EIP-55
+    ///
+    /// Only used together with [`checksum`](Options::checksum).
+    ///
+    /// ```rust
+    /// use velas_address_rust::*;
+    ///
+    /// let options = Options::new()
+    ///     .checksum(true)
+    ///     .chain_id(Some(VELAS_MAINNET_CHAIN_ID));
+    /// let eth_addr = vlx_to_eth_with("V5dJeCa7bmkqmZF53TqjRbnB4fG6hxuu4f", options).unwrap();
+    /// assert!(eth_to_vlx_with(&eth_addr, options).is_ok());
+    /// ```
+    ///
+    pub fn chain_id(mut self, chain_id: Option<u64>) -> Self {
+        self.chain_id = chain_id;
+        self
+    }
+}
@@ -0,0 +1,94 @@
+//! Python bindings, build with `maturin build --release`
+//!
+//! The module is imported as `velas_address`, failed conversions raise
+//! `velas_address.AddressError`, a subclass of `ValueError`. The `*_batch`
+//! functions convert a list at once and return `None` for invalid entries.
+
+use crate::{AddressError, Options};
+use pyo3::prelude::*;
+
+mod exceptions {
+    pyo3::create_exception!(velas_address, AddressError, pyo3::exceptions::PyValueError);
+}
+
+fn to_py_error(err: AddressError) -> PyErr {
+    exceptions::AddressError::new_err(err.to_string())
+}
+
+fn options(checksum: bool, lenient: bool) -> Options {
+    Options::new().checksum(checksum).lenient(lenient)
+}
+
+/// Convert ETH address to VLX address, verify the EIP-55 checksum if `checksum` is true
+#[pyfunction]
+#[pyo3(signature = (address, checksum = false, lenient = false))]
+fn eth_to_vlx(address: &str, checksum: bool, lenient: bool) -> PyResult<String> {
+    crate::eth_to_vlx_with(address, options(checksum, lenient)).map_err(to_py_error)
+}
+
+/// Convert VLX address to ETH address, EIP-55 checksummed if `checksum` is true
+#[pyfunction]
+#[pyo3(signature = (address, checksum = false, lenient = false))]
+fn vlx_to_eth(address: &str, checksum: bool, lenient: bool) -> PyResult<String> {
+    crate::vlx_to_eth_with(address, options(checksum, lenient)).map_err(to_py_error)
+}
+
+/// Check that an address is valid in any supported format
+#[pyfunction]
+fn is_valid(address: &str) -> bool {
+    crate::detect(address).is_ok()
+}
+
+/// Convert a list of ETH addresses, `None` for invalid or missing entries
+#[pyfunction]
+#[pyo3(signature = (addresses, checksum = false, lenient = false))]
+fn eth_to_vlx_batch(
+    addresses: Vec<Option<String>>,
+    checksum: bool,
+    lenient: bool,
+) -> Vec<Option<String>> {
+    let options = options(checksum, lenient);
+    addresses
+        .iter()
+        .map(|address| crate::eth_to_vlx_with(address.as_deref()?, options).ok())
+        .collect()
+}
+
+/// Convert a list of VLX addresses, `None` for invalid or missing entries
+#[pyfunction]
+#[pyo3(signature = (addresses, checksum = false, lenient = false))]
+fn vlx_to_eth_batch(
+    addresses: Vec<Option<String>>,
+    checksum: bool,
+    lenient: bool,
+) -> Vec<Option<String>> {
+    let options = options(checksum, lenient);
+    addresses
+        .iter()
+        .map(|address| crate::vlx_to_eth_with(address.as_deref()?, options).ok())
+        .collect()
+}
+
+/// Check a list of addresses, missing entries are invalid
+#[pyfunction]
+fn is_valid_batch(addresses: Vec<Option<String>>) -> Vec<bool> {
+    addresses
+        .iter()
+        .map(|address| address.as_deref().is_some_and(is_valid))
+        .collect()
+}
+
+#[pymodule]
+fn velas_address(m: &Bound<'_, PyModule>) -> PyResult<()> {
+    m.add(
+        "AddressError",
+        m.py().get_type::<exceptions::AddressError>(),
+    )?;
+    m.add_function(wrap_pyfunction!(eth_to_vlx, m)?)?;
+    m.add_function(wrap_pyfunction!(vlx_to_eth, m)?)?;
+    m.add_function(wrap_pyfunction!(is_valid, m)?)?;
+    m.add_function(wrap_pyfunction!(eth_to_vlx_batch, m)?)?;
+    m.add_function(wrap_pyfunction!(vlx_to_eth_batch, m)?)?;
+    m.add_function(wrap_pyfunction!(is_valid_batch, m)?)?;
+    Ok(())
+}
@@ -0,0 +1,164 @@
+//! Serde support for the address types
+//!
+//! Every address serializes as its string form and deserializes from the same
+//! format only. The `as_*` modules are for `#[serde(with = "...")]` on
+//! `EthAddress` or `VlxAddress` fields, they pick the output format and accept
+//! both `0x` and `V` addresses on input.
+//!
+//! ```rust
+//! use serde::{Deserialize, Serialize};
+//! use velas_address_rust::EthAddress;
+//!
+//! #[derive(Serialize, Deserialize)]
+//! struct Transfer {
+//!     #[serde(with = "velas_address_rust::serde::as_vlx")]
+//!     to: EthAddress,
+//! }
+//!
+//! let transfer: Transfer =
+//!     serde_json::from_str(r#"{"to":"0x32Be343B94f860124dC4fEe278FDCBD38C102D88"}"#).unwrap();
+//! assert_eq!(
+//!     serde_json::to_string(&transfer).unwrap(),
+//!     r#"{"to":"V5dJeCa7bmkqmZF53TqjRbnB4fG6hxuu4f"}"#
+//! );
+//! ```
+//!
+
+use crate::detect::parse_evm;
+use crate::{AddressError, EthAddress, NativeAddress, VlxAddress};
+use ::serde::de::{self, Deserializer, Visitor};
+use ::serde::{Deserialize, Serialize, Serializer};
+use core::fmt;
+use core::marker::PhantomData;
+use core::str::FromStr;
+
+struct FromStrVisitor<T>(&'static str, PhantomData<T>);
+
+impl<'de, T> Visitor<'de> for FromStrVisitor<T>
+where
+    T: FromStr<Err = AddressError>,
+{
+    type Value = T;
+
+    fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.0)
+    }
+
+    fn visit_str<E: de::Error>(self, address: &str) -> Result<T, E> {
+        address.parse().map_err(E::custom)
+    }
+}
+
+fn deserialize_str<'de, T, D>(deserializer: D, expecting: &'static str) -> Result<T, D::Error>
+where
+    T: FromStr<Err = AddressError>,
+    D: Deserializer<'de>,
+{
+    deserializer.deserialize_str(FromStrVisitor(expecting, PhantomData))
+}
+
+macro_rules! impl_serde {
+    ($address:ty, $expecting:expr) => {
+        impl Serialize for $address {
+            fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+                serializer.collect_str(self)
+            }
+        }
+
+        impl<'de> Deserialize<'de> for $address {
+            fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+                deserialize_str(deserializer, $expecting)
+            }
+        }
+    };
+}
+
+impl_serde!(EthAddress, "an ETH address in 0x format");
+impl_serde!(VlxAddress, "a VLX address in V format");
+impl_serde!(NativeAddress, "a Velas native address");
+
+/// ETH or VLX address, whichever format the input has
+struct EitherAddress(EthAddress);
+
+impl FromStr for EitherAddress {
+    type Err = AddressError;
+
+    fn from_str(address: &str) -> Result<Self, Self::Err> {
+        parse_evm(address).map(EitherAddress)
+    }
+}
+
+fn deserialize_either<'de, T, D>(deserializer: D) -> Result<T, D::Error>
+where
+    T: From<VlxAddress>,
+    D: Deserializer<'de>,
+{
+    let address: EitherAddress = deserialize_str(
+        deserializer,
+        "an ETH address in 0x format or VLX address in V format",
+    )?;
+    Ok(VlxAddress::from(address.0).into())
+}
+
+/// Serialize as VLX address in `V` format, deserialize from either format
+pub mod as_vlx {
+    use super::*;
+
+    pub fn serialize<T, S>(address: &T, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        T: Copy + Into<VlxAddress>,
+        S: Serializer,
+    {
+        serializer.collect_str(&(*address).into())
+    }
+
+    pub fn deserialize<'de, T, D>(deserializer: D) -> Result<T, D::Error>
+    where
+        T: From<VlxAddress>,
+        D: Deserializer<'de>,
+    {
+        deserialize_either(deserializer)
+    }
+}
+
+/// Serialize as lowercase ETH address in `0x` format, deserialize from either format
+pub mod as_eth {
+    use super::*;
+
+    pub fn serialize<T, S>(address: &T, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        T: Copy + Into<VlxAddress>,
+        S: Serializer,
+    {
+        serializer.collect_str(&EthAddress::from((*address).into()))
+    }
+
+    pub fn deserialize<'de, T, D>(deserializer: D) -> Result<T, D::Error>
+    where
+        T: From<VlxAddress>,
+        D: Deserializer<'de>,
+    {
+        deserialize_either(deserializer)
+    }
+}
+
+/// Serialize as ETH address in EIP-55 checksum format, deserialize from either format
+pub mod as_eth_checksum {
+    use super::*;
+
+    pub fn serialize<T, S>(address: &T, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        T: Copy + Into<VlxAddress>,
+        S: Serializer,
+    {
+        serializer.serialize_str(&EthAddress::from((*address).into()).to_checksum())
+    }
+
+    pub fn deserialize<'de, T, D>(deserializer: D) -> Result<T, D::Error>
+    where
+        T: From<VlxAddress>,
+        D: Deserializer<'de>,
+    {
+        deserialize_either(deserializer)
+    }
+}
@@ -0,0 +1,132 @@
+use crate::{base58, VlxAddress};
+use alloc::string::String;
+use alloc::vec::Vec;
+
+/// Characters that are commonly typed instead of a base58 digit
+const LOOK_ALIKES: &[(char, &[u8])] = &[('0', b"o"), ('O', b"o"), ('I', b"1i"), ('l', b"1i")];
+
+/// Number of digits after the `V` of a VLX address
+const DIGITS: usize = 33;
+
+/// A single typo, undone by applying it to the digits of the address
+#[derive(Clone, Copy)]
+enum Edit {
+    Substitute(usize, u8),
+    Transpose(usize),
+    Delete(usize),
+    Insert(usize, u8),
+}
+
+/// Whether `digit` is a likely replacement for `ch`, a change of case or a look-alike
+fn is_likely(ch: char, digit: u8) -> bool {
+    let look_alike = LOOK_ALIKES
+        .iter()
+        .any(|(typo, digits)| *typo == ch && digits.contains(&digit));
+
+    look_alike || (ch != digit as char && ch.eq_ignore_ascii_case(&(digit as char)))
+}
+
+/// Single edits, ordered from the most to the least likely one
+fn edits(digits: &[char]) -> impl Iterator<Item = Edit> + '_ {
+    let substitutions = move |likely: bool| {
+        (0..digits.len()).flat_map(move |position| {
+            base58::ALPHABET
+                .iter()
+                .filter(move |digit| {
+                    **digit as char != digits[position]
+                        && is_likely(digits[position], **digit) == likely
+                })
+                .map(move |digit| Edit::Substitute(position, *digit))
+        })
+    };
+
+    let transpositions = (1..digits.len())
+        .filter(move |position| digits[position - 1] != digits[*position])
+        .map(|position| Edit::Transpose(position - 1));
+
+    let deletions = (0..digits.len()).map(Edit::Delete);
+
+    let insertions = (0..=digits.len()).flat_map(|position| {
+        base58::ALPHABET
+            .iter()
+            .map(move |digit| Edit::Insert(position, *digit))
+    });
+
+    substitutions(true)
+        .chain(transpositions)
+        .chain(substitutions(false))
+        .chain(deletions)
+        .chain(insertions)
+}
+
+/// Write the address with `edit` applied to `digits` into `candidate`
+fn apply(digits: &[char], edit: Edit, candidate: &mut String) {
+    candidate.clear();
+    candidate.push('V');
+
+    for (position, ch) in digits.iter().enumerate() {
+        match edit {
+            Edit::Substitute(at, digit) if at == position => candidate.push(digit as char),
+            Edit::Transpose(at) if at == position => candidate.push(digits[at + 1]),
+            Edit::Transpose(at) if at + 1 == position => candidate.push(digits[at]),
+            Edit::Delete(at) if at == position => {}
+            Edit::Insert(at, digit) if at == position => {
+                candidate.push(digit as char);
+                candidate.push(*ch);
+            }
+            _ => candidate.push(*ch),
+        }
+    }
+
+    if let Edit::Insert(at, digit) = edit {
+        if at == digits.len() {
+            candidate.push(digit as char);
+        }
+    }
+}
+
+/// Suggest valid VLX addresses that are a single typo away from `address`
+///
+/// Tries every substitution of one character, transposition of two adjacent
+/// characters and insertion or deletion of one character after the `V`, and
+/// returns the candidates with a valid checksum. Changes of case and of
+/// look-alike characters like `0` for `o` come first, then transpositions,
+/// other substitutions and finally insertions and deletions. A valid address
+/// is returned as its only suggestion, input that is more than one character
+/// off the length of a VLX address has none.
+///
+/// ```rust
+/// use velas_address_rust::*;
+///
+/// let suggestions = suggest_corrections("V5dJeCa7bmkqmZF53TqjRbnB4fG6hxuu4F");
+/// assert_eq!(suggestions[0].to_string(), "V5dJeCa7bmkqmZF53TqjRbnB4fG6hxuu4f");
+///
+/// let suggestions = suggest_corrections("V5dJeCa7bmkqmZF53TqjRbnB4fG6hxu4f");
+/// assert_eq!(suggestions[0].to_string(), "V5dJeCa7bmkqmZF53TqjRbnB4fG6hxuu4f");
+/// ```
+///
+pub fn suggest_corrections(address: &str) -> Vec<VlxAddress> {
+    let digits: Vec<char> = match address.strip_prefix('V') {
+        Some(digits) if (DIGITS - 1..=DIGITS + 1).contains(&digits.chars().count()) => {
+            digits.chars().collect()
+        }
+        _ => return Vec::new(),
+    };
+
+    if let Ok(address) = address.parse() {
+        return alloc::vec![address];
+    }
+
+    let mut suggestions: Vec<VlxAddress> = Vec::new();
+    let mut candidate = String::with_capacity(address.len() + 4);
+    for edit in edits(&digits) {
+        apply(&digits, edit, &mut candidate);
+        if let Ok(address) = candidate.parse() {
+            if !suggestions.contains(&address) {
+                suggestions.push(address);
+            }
+        }
+    }
+
+    suggestions
+}
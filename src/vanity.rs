@@ -0,0 +1,413 @@
+use crate::{base58, encode_vlx_into, from_private_key, EthAddress, VlxAddress};
+#[cfg(feature = "vanity-regex")]
+use regex::Regex;
+use std::error::Error;
+use std::fmt;
+use std::sync::atomic::{AtomicBool, AtomicU64, Ordering};
+use std::sync::mpsc::{self, RecvTimeoutError};
+use std::thread;
+use std::time::{Duration, Instant};
+use zeroize::Zeroize;
+
+/// Number of base58 digits after the `V` of every VLX address
+const DIGITS: usize = 33;
+
+/// secp256k1 private key that is zeroized when dropped
+pub struct PrivateKey([u8; 32]);
+
+impl PrivateKey {
+    /// Raw 32 bytes of the key
+    pub fn as_bytes(&self) -> &[u8; 32] {
+        &self.0
+    }
+}
+
+impl Drop for PrivateKey {
+    fn drop(&mut self) {
+        self.0.zeroize();
+    }
+}
+
+impl fmt::Debug for PrivateKey {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str("PrivateKey(..)")
+    }
+}
+
+/// Address found by a [`VanitySearch`] together with its private key
+#[derive(Debug)]
+pub struct VanityMatch {
+    pub eth: EthAddress,
+    pub vlx: VlxAddress,
+    pub private_key: PrivateKey,
+    /// Number of keys generated by all threads
+    pub attempts: u64,
+}
+
+/// Progress of a running search, passed to the callback of
+/// [`VanitySearch::search_with_progress`]
+#[derive(Clone, Copy, Debug)]
+pub struct Progress {
+    /// Number of keys generated so far
+    pub attempts: u64,
+    pub elapsed: Duration,
+    /// Expected number of keys until a match, see [`VanitySearch::difficulty`]
+    pub difficulty: Option<f64>,
+}
+
+impl Progress {
+    /// Keys generated per second
+    pub fn rate(&self) -> f64 {
+        self.attempts as f64 / self.elapsed.as_secs_f64().max(f64::EPSILON)
+    }
+
+    /// Probability that a match would have been found after this many keys
+    pub fn probability(&self) -> Option<f64> {
+        self.difficulty
+            .map(|difficulty| 1.0 - (1.0 - 1.0 / difficulty).powf(self.attempts as f64))
+    }
+}
+
+/// Error returned for a pattern no VLX address can match
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum VanityError {
+    /// The prefix does not start with `V`
+    MissingPrefix,
+    /// The character at `position` of the prefix or suffix is not in the base58 alphabet
+    InvalidCharacter { position: usize, ch: char },
+    /// The prefix is beyond the largest VLX address or the pattern is too long
+    Unreachable,
+}
+
+impl fmt::Display for VanityError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            VanityError::MissingPrefix => write!(f, "Invalid pattern: prefix must start with V"),
+            VanityError::InvalidCharacter { position, ch } => write!(
+                f,
+                "Invalid pattern: invalid character {:?} at position {}",
+                ch, position
+            ),
+            VanityError::Unreachable => write!(f, "Invalid pattern: no address can match"),
+        }
+    }
+}
+
+impl Error for VanityError {}
+
+/// Searches random secp256k1 keys for a VLX address matching a pattern
+///
+/// The prefix is matched against the whole address including the leading
+/// `V`, so every prefix has to start with it. Keys are generated from the
+/// system random number generator on all available threads by default.
+///
+/// ```rust
+/// use velas_address_rust::*;
+///
+/// let search = VanitySearch::new().prefix("V2").suffix("x").case_sensitive(false);
+/// let found = search.search().unwrap();
+///
+/// let vlx = found.vlx.to_string();
+/// assert!(vlx.starts_with("V2"));
+/// assert!(vlx.to_lowercase().ends_with('x'));
+/// assert_eq!(from_private_key(found.private_key.as_bytes()).unwrap(), (found.eth, found.vlx));
+/// ```
+///
+#[derive(Clone, Debug)]
+pub struct VanitySearch {
+    prefix: String,
+    suffix: String,
+    case_sensitive: bool,
+    threads: usize,
+    #[cfg(feature = "vanity-regex")]
+    regex: Option<Regex>,
+}
+
+impl Default for VanitySearch {
+    fn default() -> Self {
+        VanitySearch {
+            prefix: String::new(),
+            suffix: String::new(),
+            case_sensitive: true,
+            threads: thread::available_parallelism().map_or(1, |threads| threads.get()),
+            #[cfg(feature = "vanity-regex")]
+            regex: None,
+        }
+    }
+}
+
+/// Characters of the base58 alphabet that `ch` stands for
+fn variants(ch: char, case_sensitive: bool) -> Vec<u8> {
+    let chars = if case_sensitive {
+        vec![ch]
+    } else {
+        vec![ch.to_ascii_uppercase(), ch.to_ascii_lowercase()]
+    };
+
+    let mut variants: Vec<u8> = chars
+        .into_iter()
+        .filter(|ch| ch.is_ascii() && base58::ALPHABET.contains(&(*ch as u8)))
+        .map(|ch| ch as u8)
+        .collect();
+    variants.dedup();
+    variants
+}
+
+/// Value of a base58 digit
+fn digit(ch: u8) -> usize {
+    base58::ALPHABET
+        .iter()
+        .position(|digit| *digit == ch)
+        .unwrap()
+}
+
+/// Number of strings `pattern` stands for
+fn combinations(pattern: &str, case_sensitive: bool) -> f64 {
+    pattern
+        .chars()
+        .map(|ch| variants(ch, case_sensitive).len() as f64)
+        .product()
+}
+
+impl VanitySearch {
+    /// Search without pattern, set at least one of prefix, suffix or regex
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Start of the address, including the leading `V`
+    pub fn prefix(mut self, prefix: &str) -> Self {
+        self.prefix = prefix.to_string();
+        self
+    }
+
+    /// End of the address
+    pub fn suffix(mut self, suffix: &str) -> Self {
+        self.suffix = suffix.to_string();
+        self
+    }
+
+    /// Match prefix and suffix case-sensitively, true by default
+    pub fn case_sensitive(mut self, case_sensitive: bool) -> Self {
+        self.case_sensitive = case_sensitive;
+        self
+    }
+
+    /// Number of threads generating keys, all available cores by default
+    pub fn threads(mut self, threads: usize) -> Self {
+        self.threads = threads.max(1);
+        self
+    }
+
+    /// Regex the whole address has to match in addition to prefix and suffix,
+    /// use `(?i)` to match it case-insensitively
+    #[cfg(feature = "vanity-regex")]
+    pub fn regex(mut self, regex: Regex) -> Self {
+        self.regex = Some(regex);
+        self
+    }
+
+    fn validate(&self) -> Result<(), VanityError> {
+        let mut prefix = self.prefix.chars();
+        if let Some(ch) = prefix.next() {
+            if !variants(ch, self.case_sensitive).contains(&b'V') {
+                return Err(VanityError::MissingPrefix);
+            }
+        }
+
+        let digits = prefix.clone().count();
+        let suffix = self.suffix.chars().count();
+        if digits > DIGITS || suffix > DIGITS {
+            return Err(VanityError::Unreachable);
+        }
+
+        // Addresses are fixed-width numbers in base58, so the smallest variant
+        // of the prefix must not be above the largest address
+        let mut max = [0u8; base58::encoded_len(24)];
+        base58::encode_into(&[0xff; 24], base58::ALPHABET, &mut max);
+        let mut below = false;
+
+        for (position, ch) in prefix.enumerate() {
+            let smallest = variants(ch, self.case_sensitive)
+                .into_iter()
+                .map(digit)
+                .min()
+                .ok_or(VanityError::InvalidCharacter {
+                    position: position + 1,
+                    ch,
+                })?;
+
+            let max = digit(max[position]);
+            if !below && smallest > max {
+                return Err(VanityError::Unreachable);
+            }
+            below |= smallest < max;
+        }
+
+        for (position, ch) in self.suffix.chars().enumerate() {
+            if variants(ch, self.case_sensitive).is_empty() {
+                return Err(VanityError::InvalidCharacter {
+                    position: position + 1 + DIGITS - suffix,
+                    ch,
+                });
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Expected number of keys until a match, `None` if a regex is set
+    ///
+    /// ```rust
+    /// use velas_address_rust::*;
+    ///
+    /// let difficulty = VanitySearch::new().suffix("xyz").difficulty().unwrap();
+    /// assert_eq!(difficulty.round(), 58f64.powi(3));
+    /// ```
+    ///
+    pub fn difficulty(&self) -> Option<f64> {
+        #[cfg(feature = "vanity-regex")]
+        {
+            if self.regex.is_some() {
+                return None;
+            }
+        }
+
+        // The digits after the `V` encode a value below 2^192, which makes the
+        // leading digits less varied than the trailing ones
+        let digits: String = self.prefix.chars().skip(1).collect();
+        let prefix = 2f64.powi(192)
+            / 58f64.powi(DIGITS.saturating_sub(digits.chars().count()) as i32)
+            / combinations(&digits, self.case_sensitive);
+
+        let suffix = 58f64.powi(self.suffix.chars().count() as i32)
+            / combinations(&self.suffix, self.case_sensitive);
+
+        Some(prefix.max(1.0) * suffix)
+    }
+
+    fn matches(&self, address: &str) -> bool {
+        let (prefix, suffix) = (self.prefix.as_str(), self.suffix.as_str());
+
+        let found = if self.case_sensitive {
+            address.starts_with(prefix) && address.ends_with(suffix)
+        } else {
+            address.len() >= prefix.len().max(suffix.len())
+                && address[..prefix.len()].eq_ignore_ascii_case(prefix)
+                && address[address.len() - suffix.len()..].eq_ignore_ascii_case(suffix)
+        };
+
+        #[cfg(feature = "vanity-regex")]
+        {
+            if let Some(regex) = &self.regex {
+                return found && regex.is_match(address);
+            }
+        }
+
+        found
+    }
+
+    fn worker(&self, found: &AtomicBool, attempts: &AtomicU64) -> Option<VanityMatch> {
+        let mut key = [0u8; 32];
+        let mut buf = [0u8; 34];
+        let mut count = 0;
+        let mut result = None;
+
+        while result.is_none() && !found.load(Ordering::Relaxed) {
+            getrandom::getrandom(&mut key).expect("system random number generator failed");
+
+            let (eth, vlx) = match from_private_key(&key) {
+                Ok(addresses) => addresses,
+                Err(_) => continue,
+            };
+            count += 1;
+
+            if self.matches(encode_vlx_into(eth.as_bytes(), &mut buf)) {
+                result = Some(VanityMatch {
+                    eth,
+                    vlx,
+                    private_key: PrivateKey(key),
+                    attempts: 0,
+                });
+            }
+
+            if count == 256 || result.is_some() {
+                attempts.fetch_add(count, Ordering::Relaxed);
+                count = 0;
+            }
+        }
+
+        key.zeroize();
+        result
+    }
+
+    /// Generate keys until one matches
+    pub fn search(&self) -> Result<VanityMatch, VanityError> {
+        self.run(None, &mut |_| {})
+    }
+
+    /// Generate keys until one matches and call `progress` every `interval`
+    /// from the calling thread
+    pub fn search_with_progress<F>(
+        &self,
+        interval: Duration,
+        mut progress: F,
+    ) -> Result<VanityMatch, VanityError>
+    where
+        F: FnMut(&Progress),
+    {
+        self.run(Some(interval), &mut progress)
+    }
+
+    fn run(
+        &self,
+        interval: Option<Duration>,
+        progress: &mut dyn FnMut(&Progress),
+    ) -> Result<VanityMatch, VanityError> {
+        self.validate()?;
+
+        let found = AtomicBool::new(false);
+        let attempts = AtomicU64::new(0);
+        let difficulty = self.difficulty();
+        let start = Instant::now();
+
+        let mut result = thread::scope(|scope| {
+            let (sender, receiver) = mpsc::channel();
+
+            for _ in 0..self.threads {
+                let sender = sender.clone();
+                let (found, attempts) = (&found, &attempts);
+                scope.spawn(move || {
+                    if let Some(result) = self.worker(found, attempts) {
+                        let _ = sender.send(result);
+                    }
+                });
+            }
+            drop(sender);
+
+            let result = loop {
+                let interval = match interval {
+                    Some(interval) => interval,
+                    None => break receiver.recv().ok(),
+                };
+
+                match receiver.recv_timeout(interval) {
+                    Ok(result) => break Some(result),
+                    Err(RecvTimeoutError::Timeout) => progress(&Progress {
+                        attempts: attempts.load(Ordering::Relaxed),
+                        elapsed: start.elapsed(),
+                        difficulty,
+                    }),
+                    Err(RecvTimeoutError::Disconnected) => break None,
+                }
+            };
+
+            found.store(true, Ordering::Relaxed);
+            result
+        })
+        .expect("all search threads failed");
+
+        result.attempts = attempts.load(Ordering::Relaxed);
+        Ok(result)
+    }
+}
@@ -0,0 +1,127 @@
+//! WebAssembly bindings, build with `cargo rustc --lib --target wasm32-unknown-unknown --features wasm --crate-type cdylib`
+
+use crate::{AddressError, AddressKind, Options};
+use js_sys::{Error, Object, Reflect};
+use wasm_bindgen::prelude::*;
+use wasm_bindgen::JsCast;
+
+#[wasm_bindgen(typescript_custom_section)]
+const TYPESCRIPT: &str = r#"
+export type AddressErrorKind =
+    | "MissingPrefix"
+    | "InvalidLength"
+    | "OddLength"
+    | "InvalidCharacter"
+    | "ChecksumMismatch"
+    | "NonCanonicalPadding"
+    | "InvalidChecksumCase"
+    | "InvalidKey"
+    | "InvalidMnemonic"
+    | "InvalidDerivationPath"
+    | "NonCanonical";
+
+/** Error thrown by all functions of this module */
+export interface AddressError extends Error {
+    name: "AddressError";
+    kind: AddressErrorKind;
+    /** Set for `InvalidLength` as numbers and for `ChecksumMismatch` as hex strings */
+    expected?: number | string;
+    found?: number | string;
+    /** Set for `InvalidCharacter` and `InvalidChecksumCase` */
+    position?: number;
+    char?: string;
+}
+
+export interface DetectedAddress {
+    kind: "eth" | "vlx" | "native";
+    /** Address in its canonical form */
+    address: string;
+    /** Decoded payload as lowercase hex, 20 bytes for EVM and 32 bytes for native addresses */
+    payload: string;
+}
+"#;
+
+#[wasm_bindgen]
+extern "C" {
+    #[wasm_bindgen(typescript_type = "DetectedAddress")]
+    pub type DetectedAddress;
+}
+
+fn set(object: &Object, key: &str, value: JsValue) {
+    Reflect::set(object, &JsValue::from_str(key), &value).unwrap();
+}
+
+fn to_js_error(err: AddressError) -> JsValue {
+    let error = Error::new(&err.to_string());
+    error.set_name("AddressError");
+
+    let kind = match err {
+        AddressError::MissingPrefix => "MissingPrefix",
+        AddressError::InvalidLength { expected, found } => {
+            set(&error, "expected", (expected as u32).into());
+            set(&error, "found", (found as u32).into());
+            "InvalidLength"
+        }
+        AddressError::OddLength => "OddLength",
+        AddressError::InvalidCharacter { position, ch } => {
+            set(&error, "position", (position as u32).into());
+            set(&error, "char", ch.to_string().into());
+            "InvalidCharacter"
+        }
+        AddressError::ChecksumMismatch { expected, found } => {
+            set(&error, "expected", hex::encode(expected).into());
+            set(&error, "found", hex::encode(found).into());
+            "ChecksumMismatch"
+        }
+        AddressError::NonCanonicalPadding => "NonCanonicalPadding",
+        AddressError::InvalidChecksumCase { position, ch } => {
+            set(&error, "position", (position as u32).into());
+            set(&error, "char", ch.to_string().into());
+            "InvalidChecksumCase"
+        }
+        AddressError::InvalidKey => "InvalidKey",
+        AddressError::InvalidMnemonic => "InvalidMnemonic",
+        AddressError::InvalidDerivationPath => "InvalidDerivationPath",
+        AddressError::NonCanonical => "NonCanonical",
+    };
+    set(&error, "kind", kind.into());
+
+    error.into()
+}
+
+/// Convert ETH address to VLX address
+#[wasm_bindgen(js_name = ethToVlx)]
+pub fn eth_to_vlx(address: &str) -> Result<String, JsValue> {
+    crate::eth_to_vlx(address).map_err(to_js_error)
+}
+
+/// Convert VLX address to ETH address, EIP-55 checksummed if `checksum` is true
+#[wasm_bindgen(js_name = vlxToEth)]
+pub fn vlx_to_eth(address: &str, checksum: Option<bool>) -> Result<String, JsValue> {
+    let options = Options::new().checksum(checksum.unwrap_or(false));
+    crate::vlx_to_eth_with(address, options).map_err(to_js_error)
+}
+
+/// Check that an address is valid in any supported format
+#[wasm_bindgen]
+pub fn validate(address: &str) -> bool {
+    crate::detect(address).is_ok()
+}
+
+/// Detect the format of an address and decode it
+#[wasm_bindgen]
+pub fn detect(address: &str) -> Result<DetectedAddress, JsValue> {
+    let kind = crate::detect(address).map_err(to_js_error)?;
+
+    let object = Object::new();
+    let name = match kind {
+        AddressKind::Eth(_) => "eth",
+        AddressKind::Vlx(_) => "vlx",
+        AddressKind::Native(_) => "native",
+    };
+    set(&object, "kind", name.into());
+    set(&object, "address", kind.to_string().into());
+    set(&object, "payload", hex::encode(kind.as_bytes()).into());
+
+    Ok(object.unchecked_into())
+}
@@ -0,0 +1,70 @@
+#include <stdio.h>
+#include <string.h>
+
+#include "velas_address.h"
+
+static int failures = 0;
+
+#define CHECK(cond)                                                     \
+    do {                                                                \
+        if (!(cond)) {                                                  \
+            fprintf(stderr, "%s:%d: check failed: %s\n", __FILE__,     \
+                    __LINE__, #cond);                                   \
+            failures++;                                                 \
+        }                                                               \
+    } while (0)
+
+int main(void) {
+    char out[VELAS_ADDRESS_MAX_LEN];
+
+    CHECK(velas_eth_to_vlx("0x32Be343B94f860124dC4fEe278FDCBD38C102D88", 0, out,
+                           sizeof(out)) == VELAS_OK);
+    CHECK(strcmp(out, "V5dJeCa7bmkqmZF53TqjRbnB4fG6hxuu4f") == 0);
+
+    CHECK(velas_vlx_to_eth("V5dJeCa7bmkqmZF53TqjRbnB4fG6hxuu4f", 0, out,
+                           sizeof(out)) == VELAS_OK);
+    CHECK(strcmp(out, "0x32be343b94f860124dc4fee278fdcbd38c102d88") == 0);
+
+    CHECK(velas_vlx_to_eth("V5dJeCa7bmkqmZF53TqjRbnB4fG6hxuu4f", VELAS_FLAG_CHECKSUM,
+                           out, sizeof(out)) == VELAS_OK);
+    CHECK(strcmp(out, "0x32Be343B94f860124dC4fEe278FDCBD38C102D88") == 0);
+
+    CHECK(velas_vlx_to_eth("VA4oQ7mNj", VELAS_FLAG_LENIENT, out, sizeof(out)) ==
+          VELAS_OK);
+    CHECK(strcmp(out, "0x1234") == 0);
+
+    CHECK(velas_eth_to_vlx("0x5aAeb6053F3E94C9b9A09f33669435E7Ef1BeAeD",
+                           VELAS_FLAG_CHECKSUM, out, sizeof(out)) ==
+          VELAS_ERROR_INVALID_CHECKSUM_CASE);
+    CHECK(velas_eth_to_vlx("32Be343B94f860124dC4fEe278FDCBD38C102D88", 0, out,
+                           sizeof(out)) == VELAS_ERROR_MISSING_PREFIX);
+    CHECK(velas_vlx_to_eth("V5dJeCa7bmkqmZF53TqjRbnB4fG6hxuu4g", 0, out,
+                           sizeof(out)) == VELAS_ERROR_CHECKSUM_MISMATCH);
+    CHECK(velas_vlx_to_eth("VA4oQ7mNj", 0, out, sizeof(out)) ==
+          VELAS_ERROR_INVALID_LENGTH);
+    CHECK(velas_vlx_to_eth("V15dJeCa7bmkqmZF53TqjRbnB4fG6hxuu4f", 0, out,
+                           sizeof(out)) == VELAS_ERROR_NON_CANONICAL);
+    CHECK(velas_eth_to_vlx("0x123", VELAS_FLAG_LENIENT, out, sizeof(out)) ==
+          VELAS_ERROR_ODD_LENGTH);
+
+    CHECK(velas_eth_to_vlx("0x32Be343B94f860124dC4fEe278FDCBD38C102D88", 0, out,
+                           34) == VELAS_ERROR_BUFFER_TOO_SMALL);
+    CHECK(velas_eth_to_vlx(NULL, 0, out, sizeof(out)) == VELAS_ERROR_NULL_POINTER);
+    CHECK(velas_eth_to_vlx("0x32Be343B94f860124dC4fEe278FDCBD38C102D88", 0, NULL,
+                           0) == VELAS_ERROR_NULL_POINTER);
+    CHECK(velas_vlx_to_eth("V\xff", 0, out, sizeof(out)) == VELAS_ERROR_INVALID_UTF8);
+
+    CHECK(velas_validate("V5dJeCa7bmkqmZF53TqjRbnB4fG6hxuu4f") == VELAS_OK);
+    CHECK(velas_validate("Vote111111111111111111111111111111111111111") == VELAS_OK);
+    CHECK(velas_validate("0x32be") == VELAS_ERROR_INVALID_LENGTH);
+    CHECK(velas_validate(NULL) == VELAS_ERROR_NULL_POINTER);
+
+    CHECK(strcmp(velas_error_message(VELAS_ERROR_MISSING_PREFIX),
+                 "Invalid address: missing prefix") == 0);
+    CHECK(strcmp(velas_error_message(1000), "Unknown error") == 0);
+
+    if (failures == 0) {
+        printf("ok\n");
+    }
+    return failures == 0 ? 0 : 1;
+}
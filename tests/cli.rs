@@ -0,0 +1,149 @@
+#![cfg(feature = "cli")]
+
+use std::io::Write;
+use std::process::{Command, Output, Stdio};
+
+fn run(args: &[&str], stdin: &str) -> Output {
+    let mut child = Command::new(env!("CARGO_BIN_EXE_velas-address"))
+        .args(args)
+        .stdin(Stdio::piped())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped())
+        .spawn()
+        .unwrap();
+
+    child
+        .stdin
+        .take()
+        .unwrap()
+        .write_all(stdin.as_bytes())
+        .unwrap();
+
+    child.wait_with_output().unwrap()
+}
+
+#[test]
+fn convert() {
+    let output = run(
+        &["to-vlx", "0x32Be343B94f860124dC4fEe278FDCBD38C102D88"],
+        "",
+    );
+    assert_eq!(output.status.code(), Some(0));
+    assert_eq!(output.stdout, b"V5dJeCa7bmkqmZF53TqjRbnB4fG6hxuu4f\n");
+
+    let output = run(
+        &["to-eth", "--checksum"],
+        "V5dJeCa7bmkqmZF53TqjRbnB4fG6hxuu4f\n\nVQLbz7JHiBTspS962RLKV8GndWFwdcRndD\n",
+    );
+    assert_eq!(output.status.code(), Some(0));
+    assert_eq!(
+        output.stdout,
+        &b"0x32Be343B94f860124dC4fEe278FDCBD38C102D88\n0xFFfFfFffFFfffFFfFFfFFFFFffFFFffffFfFFFfF\n"[..]
+    );
+}
+
+#[test]
+fn exit_codes() {
+    let output = run(&["to-vlx", "32Be343B94f860124dC4fEe278FDCBD38C102D88"], "");
+    assert_eq!(output.status.code(), Some(10));
+    assert!(output.stdout.is_empty());
+
+    let output = run(&["to-eth", "V5dJeCa7bmkqmZF53TqjRbnB4fG6hxuu4g"], "");
+    assert_eq!(output.status.code(), Some(13));
+
+    let output = run(&["to-eth", "V15dJeCa7bmkqmZF53TqjRbnB4fG6hxuu4f"], "");
+    assert_eq!(output.status.code(), Some(19));
+
+    let output = run(&["to-vlx", "0x32Be343B94f860124dC4fEe278FDCBD38C102D8"], "");
+    assert_eq!(output.status.code(), Some(22));
+
+    let output = run(
+        &["validate", "V5dJeCa7bmkqmZF53TqjRbnB4fG6hxuu4f", "0x32be"],
+        "",
+    );
+    assert_eq!(output.status.code(), Some(11));
+    assert_eq!(output.stdout, b"valid\n");
+}
+
+#[test]
+fn json() {
+    let output = run(
+        &["--json", "detect"],
+        "V5dJeCa7bmkqmZF53TqjRbnB4fG6hxuu4f\n0x32be\n",
+    );
+    assert_eq!(output.status.code(), Some(11));
+
+    let lines: Vec<serde_json::Value> = String::from_utf8(output.stdout)
+        .unwrap()
+        .lines()
+        .map(|line| serde_json::from_str(line).unwrap())
+        .collect();
+    assert_eq!(lines[0]["kind"], "vlx");
+    assert_eq!(
+        lines[0]["payload"],
+        "32be343b94f860124dc4fee278fdcbd38c102d88"
+    );
+    assert_eq!(lines[1]["code"], 11);
+    assert_eq!(lines[1]["input"], "0x32be");
+}
+
+#[test]
+fn batch() {
+    let output = run(
+        &["batch", "--column", "address", "--to", "vlx"],
+        "id,address\n1,0x32Be343B94f860124dC4fEe278FDCBD38C102D88\n2,0x12\n",
+    );
+    assert_eq!(output.status.code(), Some(11));
+    assert_eq!(
+        String::from_utf8(output.stdout).unwrap(),
+        "id,address,address_vlx,address_status\n\
+         1,0x32Be343B94f860124dC4fEe278FDCBD38C102D88,V5dJeCa7bmkqmZF53TqjRbnB4fG6hxuu4f,valid\n\
+         2,0x12,,\"Invalid address: expected 20 bytes, found 1\"\n"
+    );
+    assert_eq!(output.stderr, b"2 rows, 1 errors\n");
+
+    let output = run(
+        &["batch", "--format", "jsonl", "--column", "a", "--to", "eth"],
+        "{\"a\":\"V5dJeCa7bmkqmZF53TqjRbnB4fG6hxuu4f\"}\n",
+    );
+    assert_eq!(output.status.code(), Some(0));
+    assert_eq!(
+        output.stdout,
+        &b"{\"a\":\"V5dJeCa7bmkqmZF53TqjRbnB4fG6hxuu4f\",\"a_eth\":\"0x32be343b94f860124dc4fee278fdcbd38c102d88\",\"a_status\":\"valid\"}\n"[..]
+    );
+
+    let output = run(
+        &["batch", "--format", "jsonl", "--column", "a", "--to", "eth"],
+        "not json\n{\"a\":\"V5dJeCa7bmkqmZF53TqjRbnB4fG6hxuu4f\"}\n",
+    );
+    assert_eq!(output.status.code(), Some(21));
+    assert_eq!(output.stderr, b"2 rows, 1 errors\n");
+
+    let output = run(&["batch", "--column", "missing", "--to", "vlx"], "id\n1\n");
+    assert_eq!(output.status.code(), Some(1));
+}
+
+#[test]
+fn vanity() {
+    let output = run(&["vanity", "--prefix", "V2", "--threads", "2"], "");
+    assert_eq!(output.status.code(), Some(0));
+
+    let stdout = String::from_utf8(output.stdout).unwrap();
+    let fields: Vec<&str> = stdout.trim_end().split('\t').collect();
+    assert_eq!(fields.len(), 3);
+    assert!(fields[0].starts_with("V2"));
+    assert_eq!(
+        run(&["to-eth", "--checksum", fields[0]], "").stdout,
+        format!("{}\n", fields[1]).as_bytes()
+    );
+
+    let output = run(&["--json", "vanity", "--suffix", "z", "-i"], "");
+    assert_eq!(output.status.code(), Some(0));
+    let json: serde_json::Value = serde_json::from_slice(&output.stdout).unwrap();
+    assert!(json["vlx"].as_str().unwrap().to_lowercase().ends_with('z'));
+    assert_eq!(json["private_key"].as_str().unwrap().len(), 64);
+
+    let output = run(&["vanity", "--prefix", "VR"], "");
+    assert_eq!(output.status.code(), Some(1));
+    assert!(output.stdout.is_empty());
+}
@@ -0,0 +1,65 @@
+#![cfg(feature = "ffi")]
+
+use std::env;
+use std::path::PathBuf;
+use std::process::Command;
+
+/// Directory with the library artifacts, `target/<profile>`
+fn target_dir() -> PathBuf {
+    let mut dir = env::current_exe().unwrap();
+    dir.pop();
+    if dir.ends_with("deps") {
+        dir.pop();
+    }
+    dir
+}
+
+#[test]
+fn c_program() {
+    let manifest_dir = PathBuf::from(env!("CARGO_MANIFEST_DIR"));
+    let target_dir = target_dir();
+    let program = target_dir.join("ffi_test");
+
+    // The static library needs its own build in a separate target directory
+    // to keep the rlib of the doctests intact
+    let mut cargo = Command::new(env!("CARGO"));
+    cargo
+        .args(["rustc", "--lib", "--crate-type", "staticlib"])
+        .args(["--no-default-features", "--features", "ffi"])
+        .arg("--manifest-path")
+        .arg(manifest_dir.join("Cargo.toml"))
+        .arg("--target-dir")
+        .arg(target_dir.join("ffi"));
+    let profile = if cfg!(debug_assertions) {
+        "debug"
+    } else {
+        cargo.arg("--release");
+        "release"
+    };
+    let status = cargo.status().unwrap();
+    assert!(status.success());
+
+    let status = Command::new(env::var("CC").unwrap_or_else(|_| "cc".to_string()))
+        .arg(manifest_dir.join("tests/c/ffi_test.c"))
+        .arg("-I")
+        .arg(manifest_dir.join("include"))
+        .arg(
+            target_dir
+                .join("ffi")
+                .join(profile)
+                .join("libvelas_address_rust.a"),
+        )
+        .args(["-lpthread", "-ldl", "-lm", "-o"])
+        .arg(&program)
+        .status()
+        .unwrap();
+    assert!(status.success());
+
+    let output = Command::new(&program).output().unwrap();
+    assert!(
+        output.status.success(),
+        "{}",
+        String::from_utf8_lossy(&output.stderr)
+    );
+    assert_eq!(output.stdout, b"ok\n");
+}
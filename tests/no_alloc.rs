@@ -0,0 +1,39 @@
+use std::alloc::{GlobalAlloc, Layout, System};
+use std::sync::atomic::{AtomicUsize, Ordering};
+use velas_address_rust::*;
+
+struct CountingAllocator;
+
+static ALLOCATIONS: AtomicUsize = AtomicUsize::new(0);
+
+unsafe impl GlobalAlloc for CountingAllocator {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        ALLOCATIONS.fetch_add(1, Ordering::SeqCst);
+        System.alloc(layout)
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        System.dealloc(ptr, layout)
+    }
+}
+
+#[global_allocator]
+static GLOBAL: CountingAllocator = CountingAllocator;
+
+#[test]
+fn encode_decode_without_allocations() {
+    let mut buf = [0u8; 34];
+    let before = ALLOCATIONS.load(Ordering::SeqCst);
+
+    let address = decode_vlx("V5dJeCa7bmkqmZF53TqjRbnB4fG6hxuu4f").unwrap();
+    let padded = decode_vlx("V111111111111111111111111112jSS6vy").unwrap();
+    let invalid = decode_vlx("V5dJeCa7bmkqmZF53TqjRbnB4fG6hxuu4g").is_err();
+    let encoded = encode_vlx_into(&address, &mut buf) == "V5dJeCa7bmkqmZF53TqjRbnB4fG6hxuu4f";
+
+    let after = ALLOCATIONS.load(Ordering::SeqCst);
+
+    assert_eq!(before, after);
+    assert_eq!(padded[19], 0x0f);
+    assert!(invalid);
+    assert!(encoded);
+}
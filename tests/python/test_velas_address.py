@@ -0,0 +1,75 @@
+import pytest
+
+import velas_address
+
+# Same vectors as the `it_works` test of the crate
+ETH_ADDRESSES = [
+    "0x32Be343B94f860124dC4fEe278FDCBD38C102D88",
+    "0x000000000000000000000000000000000000000f",
+    "0xf000000000000000000000000000000000000000",
+    "0x0000000000000000000000000000000000000001",
+    "0x1000000000000000000000000000000000000000",
+    "0x0000000000000000000000000000000000000000",
+    "0xffffffffffffffffffffffffffffffffffffffff",
+]
+
+VLX_ADDRESSES = [
+    "V5dJeCa7bmkqmZF53TqjRbnB4fG6hxuu4f",
+    "V111111111111111111111111112jSS6vy",
+    "VNt1B3HD3MghPihCxhwMxNKRerBPPbiwvZ",
+    "V111111111111111111111111111CdXjnE",
+    "V2Tbp525fpnBRiSt4iPxXkxMyf5ZX7bGAJ",
+    "V1111111111111111111111111113iMDfC",
+    "VQLbz7JHiBTspS962RLKV8GndWFwdcRndD",
+]
+
+
+@pytest.mark.parametrize("eth, vlx", zip(ETH_ADDRESSES, VLX_ADDRESSES))
+def test_it_works(eth, vlx):
+    assert velas_address.eth_to_vlx(eth) == vlx
+    assert velas_address.vlx_to_eth(vlx) == eth.lower()
+    assert velas_address.is_valid(eth)
+    assert velas_address.is_valid(vlx)
+
+
+def test_checksum():
+    vlx = "V5dJeCa7bmkqmZF53TqjRbnB4fG6hxuu4f"
+    eth = "0x32Be343B94f860124dC4fEe278FDCBD38C102D88"
+    assert velas_address.vlx_to_eth(vlx, checksum=True) == eth
+    assert velas_address.eth_to_vlx(eth, checksum=True) == vlx
+
+    with pytest.raises(velas_address.AddressError, match="wrong case"):
+        velas_address.eth_to_vlx(eth.replace("Be", "bE"), checksum=True)
+
+
+def test_lenient():
+    assert velas_address.vlx_to_eth("VA4oQ7mNj", lenient=True) == "0x1234"
+
+    with pytest.raises(velas_address.AddressError, match="expected 20 bytes, found 2"):
+        velas_address.vlx_to_eth("VA4oQ7mNj")
+
+
+def test_errors():
+    with pytest.raises(velas_address.AddressError, match="missing prefix"):
+        velas_address.eth_to_vlx("32Be343B94f860124dC4fEe278FDCBD38C102D88")
+
+    with pytest.raises(ValueError, match="Invalid checksum"):
+        velas_address.vlx_to_eth("V5dJeCa7bmkqmZF53TqjRbnB4fG6hxuu4g")
+
+    assert not velas_address.is_valid("0x32be")
+
+
+def test_batch():
+    assert velas_address.eth_to_vlx_batch(ETH_ADDRESSES) == VLX_ADDRESSES
+    assert velas_address.vlx_to_eth_batch(VLX_ADDRESSES) == [
+        eth.lower() for eth in ETH_ADDRESSES
+    ]
+
+    addresses = ["V5dJeCa7bmkqmZF53TqjRbnB4fG6hxuu4f", "V1", None]
+    assert velas_address.vlx_to_eth_batch(addresses, checksum=True) == [
+        "0x32Be343B94f860124dC4fEe278FDCBD38C102D88",
+        None,
+        None,
+    ]
+    assert velas_address.is_valid_batch(addresses) == [True, False, False]
+    assert velas_address.eth_to_vlx_batch([]) == []
@@ -0,0 +1,68 @@
+#![cfg(all(feature = "wasm", target_arch = "wasm32"))]
+
+use js_sys::Reflect;
+use velas_address_rust::wasm::*;
+use wasm_bindgen::{JsCast, JsValue};
+use wasm_bindgen_test::*;
+
+fn get(value: &JsValue, key: &str) -> JsValue {
+    Reflect::get(value, &JsValue::from_str(key)).unwrap()
+}
+
+#[wasm_bindgen_test]
+fn convert() {
+    assert_eq!(
+        eth_to_vlx("0x32Be343B94f860124dC4fEe278FDCBD38C102D88").unwrap(),
+        "V5dJeCa7bmkqmZF53TqjRbnB4fG6hxuu4f"
+    );
+    assert_eq!(
+        vlx_to_eth("V5dJeCa7bmkqmZF53TqjRbnB4fG6hxuu4f", None).unwrap(),
+        "0x32be343b94f860124dc4fee278fdcbd38c102d88"
+    );
+    assert_eq!(
+        vlx_to_eth("V5dJeCa7bmkqmZF53TqjRbnB4fG6hxuu4f", Some(true)).unwrap(),
+        "0x32Be343B94f860124dC4fEe278FDCBD38C102D88"
+    );
+}
+
+#[wasm_bindgen_test]
+fn validate_and_detect() {
+    assert!(validate("V5dJeCa7bmkqmZF53TqjRbnB4fG6hxuu4f"));
+    assert!(validate("Vote111111111111111111111111111111111111111"));
+    assert!(!validate("V5dJeCa7bmkqmZF53TqjRbnB4fG6hxuu4g"));
+
+    let detected: JsValue = detect("V5dJeCa7bmkqmZF53TqjRbnB4fG6hxuu4f").unwrap().into();
+    assert_eq!(get(&detected, "kind"), "vlx");
+    assert_eq!(
+        get(&detected, "address"),
+        "V5dJeCa7bmkqmZF53TqjRbnB4fG6hxuu4f"
+    );
+    assert_eq!(
+        get(&detected, "payload"),
+        "32be343b94f860124dc4fee278fdcbd38c102d88"
+    );
+}
+
+#[wasm_bindgen_test]
+fn errors() {
+    let error = eth_to_vlx("0x32be").unwrap_err();
+    assert!(error.is_instance_of::<js_sys::Error>());
+    assert_eq!(get(&error, "name"), "AddressError");
+    assert_eq!(get(&error, "kind"), "InvalidLength");
+    assert_eq!(get(&error, "expected"), 20);
+    assert_eq!(get(&error, "found"), 2);
+
+    let error = eth_to_vlx("0x32b").unwrap_err();
+    assert_eq!(get(&error, "kind"), "OddLength");
+
+    let error = vlx_to_eth("V5dJeCa7bmkqmZF53TqjRbnB4fG6hxuu40", None).unwrap_err();
+    assert_eq!(get(&error, "kind"), "InvalidCharacter");
+    assert_eq!(get(&error, "position"), 33);
+    assert_eq!(get(&error, "char"), "0");
+
+    let error = detect("V5dJeCa7bmkqmZF53TqjRbnB4fG6hxuu4g").err().unwrap();
+    assert_eq!(get(&error, "kind"), "ChecksumMismatch");
+
+    let error = vlx_to_eth("V15dJeCa7bmkqmZF53TqjRbnB4fG6hxuu4f", None).unwrap_err();
+    assert_eq!(get(&error, "kind"), "NonCanonical");
+}
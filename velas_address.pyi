@@ -0,0 +1,14 @@
+from typing import List, Optional
+
+class AddressError(ValueError): ...
+
+def eth_to_vlx(address: str, checksum: bool = False, lenient: bool = False) -> str: ...
+def vlx_to_eth(address: str, checksum: bool = False, lenient: bool = False) -> str: ...
+def is_valid(address: str) -> bool: ...
+def eth_to_vlx_batch(
+    addresses: List[Optional[str]], checksum: bool = False, lenient: bool = False
+) -> List[Optional[str]]: ...
+def vlx_to_eth_batch(
+    addresses: List[Optional[str]], checksum: bool = False, lenient: bool = False
+) -> List[Optional[str]]: ...
+def is_valid_batch(addresses: List[Optional[str]]) -> List[bool]: ...